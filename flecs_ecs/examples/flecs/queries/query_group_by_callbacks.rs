@@ -137,7 +137,7 @@ fn main() {
     //
 
     query.run_iter(|it, (pos,)| {
-        let group = world.entity_from_id(it.group_id());
+        let group = it.group_entity();
         let ctx = unsafe { &*(query.group_context(group) as *mut GroupCtx) };
         println!(
             "Group: {:?} - Table: [{:?}] - Counter: {}",
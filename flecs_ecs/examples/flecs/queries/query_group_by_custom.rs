@@ -104,7 +104,7 @@ fn main() {
     //
 
     query.run_iter(|it, pos| {
-        let group = world.entity_from_id(it.group_id());
+        let group = it.group_entity();
         println!(
             "Group: {:?} - Table: [{:?}]",
             group.path().unwrap(),
@@ -106,7 +106,7 @@ fn main() {
 
     query.run(|mut iter| {
         while iter.next() {
-            let group = world.entity_from_id(iter.group_id());
+            let group = iter.group_entity();
             println!(
                 "group: {:?} - Table [{}]",
                 group.path().unwrap(),
@@ -121,8 +121,7 @@ fn main() {
 
     query.set_group::<Cell_1_0>().run(|mut iter| {
         while iter.next() {
-            let world = iter.world();
-            let group = world.entity_from_id(iter.group_id());
+            let group = iter.group_entity();
             println!(
                 "group: {:?} - Table [{}]",
                 group.path().unwrap(),
@@ -170,28 +170,35 @@ impl<'a> App<'a> {
     /// If a custom run action is set, it will be invoked by this operation.
     /// The default run action calls the frame action in a loop until it returns a non-zero value.
     ///
+    /// Unlike the C++ API, the app does not take ownership of the world. The world is
+    /// cleaned up when the last [`World`] handle is dropped.
+    ///
     /// # Returns
     ///
     /// The exit code of the application.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.system::<()>().run(|it| {
+    ///     it.world().quit();
+    /// });
+    ///
+    /// let exit_code = world.app().set_target_fps(0.0).run();
+    /// assert_eq!(exit_code, 0);
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`World::quit()`]
     /// * C++ API: `app_builder::run`
     #[doc(alias = "app_builder::run")]
     pub fn run(&mut self) -> i32 {
-        let world_ptr = self.world.ptr_mut();
-        let result = unsafe { sys::ecs_app_run(world_ptr, &mut self.desc) };
-        unsafe {
-            if sys::ecs_should_quit(world_ptr) {
-                // Only free world if quit flag is set. This ensures that we won't
-                // try to cleanup the world if the app is used in an environment
-                // that takes over the main loop, like with emscripten.
-                if sys::flecs_poly_release_(world_ptr as *mut c_void) == 0 {
-                    sys::ecs_fini(world_ptr);
-                }
-            }
-        }
-        result
+        unsafe { sys::ecs_app_run(self.world.ptr_mut(), &mut self.desc) }
     }
 }
 
@@ -107,10 +107,101 @@ pub trait Doc<'a>: WorldProvider<'a> + Into<Entity> + Clone {
         self.world().set_doc_color_id(self.clone(), color);
         self
     }
+
+    /// Get human-readable name from entity.
+    ///
+    /// If the entity has no human-readable name, this returns the entity name.
+    ///
+    /// # Returns
+    ///
+    /// The human-readable name, or the entity name if no human-readable name was set.
+    /// `None` if the entity has neither.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::set_doc_name()`]
+    /// * [`World::get_doc_name_id()`]
+    /// * C++ API: `doc::get_name()`
+    fn get_doc_name(&self) -> Option<&'a str> {
+        let world = self.world();
+        doc_str(unsafe { sys::ecs_doc_get_name(world.world_ptr(), *self.clone().into()) })
+    }
+
+    /// Get brief description from entity.
+    ///
+    /// # Returns
+    ///
+    /// The brief description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::set_doc_brief()`]
+    /// * [`World::get_doc_brief_id()`]
+    /// * C++ API: `doc::get_brief()`
+    fn get_doc_brief(&self) -> Option<&'a str> {
+        let world = self.world();
+        doc_str(unsafe { sys::ecs_doc_get_brief(world.world_ptr(), *self.clone().into()) })
+    }
+
+    /// Get detailed description from entity.
+    ///
+    /// # Returns
+    ///
+    /// The detailed description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::set_doc_detail()`]
+    /// * [`World::get_doc_detail_id()`]
+    /// * C++ API: `doc::get_detail()`
+    fn get_doc_detail(&self) -> Option<&'a str> {
+        let world = self.world();
+        doc_str(unsafe { sys::ecs_doc_get_detail(world.world_ptr(), *self.clone().into()) })
+    }
+
+    /// Get link to external documentation from entity.
+    ///
+    /// # Returns
+    ///
+    /// The link to external documentation, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::set_doc_link()`]
+    /// * [`World::get_doc_link_id()`]
+    /// * C++ API: `doc::get_link()`
+    fn get_doc_link(&self) -> Option<&'a str> {
+        let world = self.world();
+        doc_str(unsafe { sys::ecs_doc_get_link(world.world_ptr(), *self.clone().into()) })
+    }
+
+    /// Get color from entity.
+    ///
+    /// # Returns
+    ///
+    /// The color, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::set_doc_color()`]
+    /// * [`World::get_doc_color_id()`]
+    /// * C++ API: `doc::get_color()`
+    fn get_doc_color(&self) -> Option<&'a str> {
+        let world = self.world();
+        doc_str(unsafe { sys::ecs_doc_get_color(world.world_ptr(), *self.clone().into()) })
+    }
 }
 
 impl<'a, T> Doc<'a> for T where T: Into<Entity> + WorldProvider<'a> + Clone {}
 
+fn doc_str<'a>(ptr: *const std::ffi::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { std::ffi::CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
+
 /// ```
 /// use flecs_ecs::{addons::doc::Doc, core::World, macros::Component};
 ///
@@ -338,6 +429,222 @@ impl World {
             sys::ecs_doc_set_color(self.ptr_mut(), *entity.into(), color.as_ptr() as *const _);
         };
     }
+
+    /// Get human-readable name from entity.
+    ///
+    /// If the entity has no human-readable name, this returns the entity name.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type that implements `ComponentId`.
+    ///
+    /// # Returns
+    ///
+    /// The human-readable name, or the entity name if no human-readable name was set.
+    /// `None` if the entity has neither.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_name()`]
+    /// * [`World::get_doc_name_id()`]
+    /// * C++ API: `world::get_doc_name()`
+    #[doc(alias = "world::get_doc_name")]
+    #[inline(always)]
+    pub fn get_doc_name<T: ComponentId>(&self) -> Option<&str> {
+        self.get_doc_name_id(T::get_id(self))
+    }
+
+    /// Get human-readable name from entity.
+    ///
+    /// If the entity has no human-readable name, this returns the entity name.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity from which to get the human-readable name.
+    ///
+    /// # Returns
+    ///
+    /// The human-readable name, or the entity name if no human-readable name was set.
+    /// `None` if the entity has neither.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_name()`]
+    /// * [`World::get_doc_name()`]
+    /// * C++ API: `world::get_doc_name()`
+    #[doc(alias = "world::get_doc_name")]
+    #[inline(always)]
+    pub fn get_doc_name_id(&self, entity: impl Into<Entity>) -> Option<&str> {
+        doc_str(unsafe { sys::ecs_doc_get_name(self.ptr_mut(), *entity.into()) })
+    }
+
+    /// Get brief description from entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type that implements `ComponentId`.
+    ///
+    /// # Returns
+    ///
+    /// The brief description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_brief()`]
+    /// * [`World::get_doc_brief_id()`]
+    /// * C++ API: `world::get_doc_brief()`
+    #[doc(alias = "world::get_doc_brief")]
+    #[inline(always)]
+    pub fn get_doc_brief<T: ComponentId>(&self) -> Option<&str> {
+        self.get_doc_brief_id(T::get_id(self))
+    }
+
+    /// Get brief description from entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity from which to get the brief description.
+    ///
+    /// # Returns
+    ///
+    /// The brief description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_brief()`]
+    /// * [`World::get_doc_brief()`]
+    /// * C++ API: `world::get_doc_brief()`
+    #[doc(alias = "world::get_doc_brief")]
+    #[inline(always)]
+    pub fn get_doc_brief_id(&self, entity: impl Into<Entity>) -> Option<&str> {
+        doc_str(unsafe { sys::ecs_doc_get_brief(self.ptr_mut(), *entity.into()) })
+    }
+
+    /// Get detailed description from entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type that implements `ComponentId`.
+    ///
+    /// # Returns
+    ///
+    /// The detailed description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_detail()`]
+    /// * [`World::get_doc_detail_id()`]
+    /// * C++ API: `world::get_doc_detail()`
+    #[doc(alias = "world::get_doc_detail")]
+    #[inline(always)]
+    pub fn get_doc_detail<T: ComponentId>(&self) -> Option<&str> {
+        self.get_doc_detail_id(T::get_id(self))
+    }
+
+    /// Get detailed description from entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity from which to get the detailed description.
+    ///
+    /// # Returns
+    ///
+    /// The detailed description, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_detail()`]
+    /// * [`World::get_doc_detail()`]
+    /// * C++ API: `world::get_doc_detail()`
+    #[doc(alias = "world::get_doc_detail")]
+    #[inline(always)]
+    pub fn get_doc_detail_id(&self, entity: impl Into<Entity>) -> Option<&str> {
+        doc_str(unsafe { sys::ecs_doc_get_detail(self.ptr_mut(), *entity.into()) })
+    }
+
+    /// Get link to external documentation from entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type that implements `ComponentId`.
+    ///
+    /// # Returns
+    ///
+    /// The link to external documentation, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_link()`]
+    /// * [`World::get_doc_link_id()`]
+    /// * C++ API: `world::get_doc_link()`
+    #[doc(alias = "world::get_doc_link")]
+    #[inline(always)]
+    pub fn get_doc_link<T: ComponentId>(&self) -> Option<&str> {
+        self.get_doc_link_id(T::get_id(self))
+    }
+
+    /// Get link to external documentation from entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity from which to get the link to external documentation.
+    ///
+    /// # Returns
+    ///
+    /// The link to external documentation, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_link()`]
+    /// * [`World::get_doc_link()`]
+    /// * C++ API: `world::get_doc_link()`
+    #[doc(alias = "world::get_doc_link")]
+    #[inline(always)]
+    pub fn get_doc_link_id(&self, entity: impl Into<Entity>) -> Option<&str> {
+        doc_str(unsafe { sys::ecs_doc_get_link(self.ptr_mut(), *entity.into()) })
+    }
+
+    /// Get color from entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type that implements `ComponentId`.
+    ///
+    /// # Returns
+    ///
+    /// The color, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_color()`]
+    /// * [`World::get_doc_color_id()`]
+    /// * C++ API: `world::get_doc_color()`
+    #[doc(alias = "world::get_doc_color")]
+    #[inline(always)]
+    pub fn get_doc_color<T: ComponentId>(&self) -> Option<&str> {
+        self.get_doc_color_id(T::get_id(self))
+    }
+
+    /// Get color from entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity from which to get the color.
+    ///
+    /// # Returns
+    ///
+    /// The color, or `None` if it was not set.
+    ///
+    /// # See also
+    ///
+    /// * [`Doc::get_doc_color()`]
+    /// * [`World::get_doc_color()`]
+    /// * C++ API: `world::get_doc_color()`
+    #[doc(alias = "world::get_doc_color")]
+    #[inline(always)]
+    pub fn get_doc_color_id(&self, entity: impl Into<Entity>) -> Option<&str> {
+        doc_str(unsafe { sys::ecs_doc_get_color(self.ptr_mut(), *entity.into()) })
+    }
 }
 
 #[test]
@@ -0,0 +1,80 @@
+//! Builtin standard units for component member metadata.
+use crate::core::{World, WorldProvider};
+use crate::sys;
+
+#[cfg(feature = "flecs_module")]
+use super::module::Module;
+
+/// Module that registers the builtin units, such as
+/// [`flecs::units::MetersPerSecond`](crate::core::flecs::units::MetersPerSecond).
+///
+/// The units module must be imported before unit entities can be used.
+///
+/// # See also
+///
+/// * [`UntypedComponent::member_unit()`](crate::core::UntypedComponent::member_unit)
+/// * C++ API: `flecs::units`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Units;
+
+#[cfg(feature = "flecs_module")]
+impl Module for Units {
+    fn module(world: &World) {
+        unsafe { sys::FlecsUnitsImport(world.ptr_mut()) };
+    }
+}
+
+impl flecs_ecs::core::DataComponent for Units {}
+
+impl flecs_ecs::core::ComponentType<flecs_ecs::core::Struct> for Units {}
+
+impl flecs_ecs::core::component_registration::registration_traits::ComponentInfo for Units {
+    const IS_GENERIC: bool = false;
+    const IS_ENUM: bool = false;
+    const IS_TAG: bool = false;
+    type TagType =
+        flecs_ecs::core::component_registration::registration_traits::FlecsFirstIsNotATag;
+    const IMPLS_CLONE: bool = true;
+    const IMPLS_DEFAULT: bool = true;
+    const IS_REF: bool = false;
+    const IS_MUT: bool = false;
+}
+impl flecs_ecs::core::component_registration::registration_traits::ComponentId for Units
+where
+    Self: 'static,
+{
+    type UnderlyingType = Units;
+    type UnderlyingEnumType = flecs_ecs::core::component_registration::NoneEnum;
+    #[inline(always)]
+    fn index() -> u32 {
+        static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
+        Self::get_or_init_index(&INDEX)
+    }
+    fn __register_lifecycle_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        flecs_ecs::core::lifecycle_traits::register_lifecycle_actions::<Units>(type_hooks);
+    }
+    fn __register_default_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_DEFAULT: bool = Units::IMPLS_DEFAULT;
+        if IMPLS_DEFAULT {
+            flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,Units>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
+        }
+    }
+    fn __register_clone_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_CLONE: bool = Units::IMPLS_CLONE;
+        if IMPLS_CLONE {
+            flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,Units>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
+        } else {
+            flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<Units>(
+                type_hooks,
+            );
+        }
+    }
+
+    fn __register_or_get_id<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+        world: impl WorldProvider<'a>,
+    ) -> sys::ecs_entity_t {
+        Self::__register_or_get_id_named::<MANUAL_REGISTRATION_CHECK>(world, "flecs::units")
+    }
+}
@@ -244,8 +244,7 @@ pub(crate) const ECS_AND: u64 = 1 << 60;
 // Builtin component ids
 pub(crate) const ECS_COMPONENT: u64 = 1;
 pub(crate) const ECS_IDENTIFIER: u64 = 2;
-pub(crate) const ECS_ITERABLE: u64 = 3;
-pub(crate) const ECS_POLY: u64 = 4;
+pub(crate) const ECS_POLY: u64 = 3;
 
 // Poly target components
 pub(crate) const ECS_QUERY: u64 = 5;
@@ -448,7 +447,7 @@ pub(crate) const ECS_BOOL_T: u64 = FLECS_HI_COMPONENT_ID + 80;
 pub(crate) const ECS_CHAR_T: u64 = FLECS_HI_COMPONENT_ID + 81;
 pub(crate) const ECS_BYTE_T: u64 = FLECS_HI_COMPONENT_ID + 82;
 pub(crate) const ECS_U8_T: u64 = FLECS_HI_COMPONENT_ID + 83;
-pub(crate) const ECS_i16_T: u64 = FLECS_HI_COMPONENT_ID + 84;
+pub(crate) const ECS_U16_T: u64 = FLECS_HI_COMPONENT_ID + 84;
 pub(crate) const ECS_U32_T: u64 = FLECS_HI_COMPONENT_ID + 85;
 pub(crate) const ECS_U64_T: u64 = FLECS_HI_COMPONENT_ID + 86;
 pub(crate) const ECS_UPTR_T: u64 = FLECS_HI_COMPONENT_ID + 87;
@@ -22,6 +22,11 @@ where
     if T::IS_ENUM {
         register_enum_data::<T>(world_ptr, id);
     }
+
+    #[cfg(feature = "flecs_meta")]
+    if !unsafe { sys::ecs_has_id(world_ptr, id, flecs::meta::StructT::ID) } {
+        T::__register_meta(UntypedComponent::new(world, id));
+    }
     id
 }
 
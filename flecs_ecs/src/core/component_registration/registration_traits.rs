@@ -269,6 +269,10 @@ pub trait ComponentId: Sized + ComponentInfo + 'static {
     #[doc(hidden)]
     fn __register_clone_hooks(_type_hooks: &mut sys::ecs_type_hooks_t) {}
 
+    // Not public API.
+    #[doc(hidden)]
+    fn __register_meta(_component: UntypedComponent<'_>) {}
+
     fn register_ctor_hook<'a>(world: impl WorldProvider<'a>)
     where
         Self: Default,
@@ -317,15 +321,16 @@ pub trait ComponentId: Sized + ComponentInfo + 'static {
     #[doc(hidden)]
     #[inline(always)]
     fn get_or_init_index(id: &AtomicU32) -> u32 {
-        match id.fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| {
-            if v != u32::MAX {
-                None
-            } else {
-                Some(Self::fetch_new_index())
-            }
-        }) {
-            Ok(_) => id.load(Ordering::Acquire),
-            Err(old) => old,
+        let current = id.load(Ordering::Acquire);
+        if current != u32::MAX {
+            return current;
+        }
+
+        // concurrent first-time callers race on a single compare-exchange, the loser adopts the winner's index
+        let new_index = Self::fetch_new_index();
+        match id.compare_exchange(u32::MAX, new_index, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => new_index,
+            Err(existing) => existing,
         }
     }
 
@@ -349,6 +354,35 @@ pub trait ComponentInfo: Sized {
     type TagType;
 }
 
+/// Describes the members of a component for reflection.
+///
+/// Use `#[derive(Meta)]` to implement this trait. The members are registered
+/// automatically when the component is registered with a world.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Meta)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// let position = world.component::<Position>();
+/// assert!(position.lookup("x").is_valid());
+/// ```
+///
+/// # See also
+///
+/// * [`Component::meta()`]
+pub trait MetaComponent {
+    /// Add the members of the type to the reflection data of `component`.
+    fn meta(component: UntypedComponent<'_>);
+}
+
 /// Caches the ids, index and name of the enum variants.
 pub trait EnumComponentInfo: ComponentType<Enum> + ComponentId {
     const SIZE_ENUM_FIELDS: u32;
@@ -482,6 +516,11 @@ pub trait FlecsCloneType {
     type Type: Clone;
 }
 
+#[doc(hidden)]
+pub trait FlecsMetaType {
+    type Type: MetaComponent;
+}
+
 #[doc(hidden)]
 pub trait FlecsPairType {
     type Type: ComponentId;
@@ -514,6 +553,19 @@ where
     type Type = T;
 }
 
+#[doc(hidden)]
+impl<T> FlecsMetaType for ConditionalTypeSelector<false, T> {
+    type Type = FlecsNoneMetaDummy;
+}
+
+#[doc(hidden)]
+impl<T> FlecsMetaType for ConditionalTypeSelector<true, T>
+where
+    T: MetaComponent,
+{
+    type Type = T;
+}
+
 #[doc(hidden)]
 pub struct FlecsFirstIsNotATag;
 
@@ -19,6 +19,12 @@ pub struct FlecsNoneDefaultDummy;
 #[derive(Clone)]
 pub struct FlecsNoneCloneDummy;
 
+pub struct FlecsNoneMetaDummy;
+
+impl super::MetaComponent for FlecsNoneMetaDummy {
+    fn meta(_component: crate::core::UntypedComponent<'_>) {}
+}
+
 pub struct ConditionalTypeSelector<const B: bool, T> {
     phantom: std::marker::PhantomData<T>,
 }
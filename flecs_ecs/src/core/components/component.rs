@@ -1,12 +1,43 @@
 //! Registering and working with components
 
-use std::{marker::PhantomData, ops::Deref, os::raw::c_void, ptr};
+use std::{ffi::c_char, marker::PhantomData, ops::Deref, os::raw::c_void, ptr};
 
 use crate::core::*;
 use crate::sys;
 
 /// Component class.
 /// Class used to register components and component metadata.
+///
+/// Returned by [`World::component()`]. The handle knows the component type `T`, so hooks such
+/// as [`Component::on_set()`] receive `&mut T`. It dereferences to [`UntypedComponent`] for
+/// reflection metadata and to [`EntityView`] for everything else that can be done with the
+/// component entity.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let world = World::new();
+///
+/// let pos = world.component::<Position>();
+/// assert_eq!(pos.id(), world.component_id::<Position>());
+///
+/// world
+///     .component::<Position>()
+///     .on_set(|_e, p| p.y = p.x * 2)
+///     .member::<i32>("x")
+///     .member::<i32>("y");
+///
+/// let e = world.entity().set(Position { x: 1, y: 0 });
+/// e.get::<&Position>(|p| assert_eq!(p.y, 2));
+/// ```
 pub struct Component<'a, T: ComponentId> {
     pub base: UntypedComponent<'a>,
     _marker: PhantomData<T>,
@@ -121,6 +152,82 @@ impl<'a, T: ComponentId> Component<'a, T> {
         }
     }
 
+    /// Copy the lifecycle hooks of the `Inner` component to this component.
+    ///
+    /// This copies the constructor, destructor, copy and move hooks, so a transparent
+    /// wrapper around `Inner` is constructed, dropped and moved the same way as `Inner`.
+    /// The `on_add`, `on_set` and `on_remove` hooks are not copied.
+    ///
+    /// Hooks must be set before the component is used.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Inner` - The component to copy the hooks from.
+    ///
+    /// # Safety
+    ///
+    /// The hooks of `Inner` are invoked on values of `T`, so `T` must have the same
+    /// layout as `Inner`, e.g. a `#[repr(transparent)]` wrapper around it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size or alignment of `T` and `Inner` differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Label(String);
+    ///
+    /// #[derive(Component, Default)]
+    /// #[repr(transparent)]
+    /// struct Title(Label);
+    ///
+    /// let world = World::new();
+    /// unsafe { world.component::<Title>().copy_hooks_from::<Label>() };
+    ///
+    /// let e = world.entity().set(Title(Label("Hello".to_string())));
+    /// e.destruct();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Component::get_hooks()`]
+    pub unsafe fn copy_hooks_from<Inner: ComponentId>(&mut self) -> &mut Self {
+        let world = self.world.world_ptr_mut();
+        let inner_id = Inner::id(self.world);
+
+        let inner_ti = sys::ecs_get_type_info(world, inner_id);
+        let ti = sys::ecs_get_type_info(world, *self.id);
+        assert!(
+            !inner_ti.is_null()
+                && !ti.is_null()
+                && (*inner_ti).size == (*ti).size
+                && (*inner_ti).alignment == (*ti).alignment,
+            "{}: {} and {} must have the same size and alignment to share hooks",
+            FlecsErrorCode::InvalidParameter,
+            std::any::type_name::<T>(),
+            std::any::type_name::<Inner>()
+        );
+
+        let inner_hooks = (*inner_ti).hooks;
+        let hooks = sys::ecs_type_hooks_t {
+            ctor: inner_hooks.ctor,
+            dtor: inner_hooks.dtor,
+            copy: inner_hooks.copy,
+            move_: inner_hooks.move_,
+            copy_ctor: inner_hooks.copy_ctor,
+            move_ctor: inner_hooks.move_ctor,
+            ctor_move_dtor: inner_hooks.ctor_move_dtor,
+            move_dtor: inner_hooks.move_dtor,
+            ..Default::default()
+        };
+        sys::ecs_set_hooks_id(world, *self.id, &hooks);
+        self
+    }
+
     /// Function to free the binding context.
     ///
     /// # See also
@@ -136,8 +243,21 @@ impl<'a, T: ComponentId> Component<'a, T> {
 
     /// Register on add hook.
     ///
+    /// Hooks are invoked synchronously by the storage, before any observer runs, and cannot be
+    /// disabled. This makes them the right place to enforce invariants of a component. A component
+    /// can have at most one hook of each kind.
+    ///
+    /// The on add hook is invoked once when the component is added to an entity, after the value
+    /// has been constructed. Setting a component the entity already has does not invoke it.
+    ///
+    /// # Arguments
+    ///
+    /// * `func`: The hook, invoked with the entity and its new component value.
+    ///
     /// # See also
     ///
+    /// * [`Component::on_remove()`]
+    /// * [`Component::on_set()`]
     /// * C++ API: `component::on_add`
     #[doc(alias = "component::on_add")]
     pub fn on_add<Func>(&mut self, func: Func) -> &mut Self
@@ -165,8 +285,17 @@ impl<'a, T: ComponentId> Component<'a, T> {
 
     /// Register on remove hook.
     ///
+    /// The on remove hook is invoked when the component is removed from an entity, or the entity
+    /// is deleted, before the value is destructed.
+    ///
+    /// # Arguments
+    ///
+    /// * `func`: The hook, invoked with the entity and the component value being removed.
+    ///
     /// # See also
     ///
+    /// * [`Component::on_add()`]
+    /// * [`Component::on_set()`]
     /// * C++ API: `component::on_remove`
     #[doc(alias = "component::on_remove")]
     pub fn on_remove<Func>(&mut self, func: Func) -> &mut Self
@@ -194,8 +323,39 @@ impl<'a, T: ComponentId> Component<'a, T> {
 
     /// Register on set hook.
     ///
+    /// The on set hook is invoked every time a value is assigned to the component, for example
+    /// with [`EntityView::set()`] or [`EntityView::modified()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `func`: The hook, invoked with the entity and the new component value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health {
+    ///     value: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Health>()
+    ///     .on_set(|_e, health: &mut Health| {
+    ///         health.value = health.value.clamp(0, 100);
+    ///     });
+    ///
+    /// let e = world.entity().set(Health { value: 150 });
+    /// e.get::<&Health>(|health| assert_eq!(health.value, 100));
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`Component::on_add()`]
+    /// * [`Component::on_remove()`]
     /// * C++ API: `component::on_set`
     #[doc(alias = "component::on_set")]
     pub fn on_set<Func>(&mut self, func: Func) -> &mut Self
@@ -221,6 +381,401 @@ impl<'a, T: ComponentId> Component<'a, T> {
         self
     }
 
+    /// Set the symbol of the component.
+    ///
+    /// By default the symbol of a component is its full Rust type path. An explicit symbol
+    /// gives the component an identifier that does not depend on the module layout, for
+    /// example to look it up from C or from a script.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The new symbol. It must be unique in the world and use `.` as separator,
+    ///   e.g. `mycrate.Position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol has no `.` separator, if it contains `::`, or if another entity
+    /// already has the symbol. Components are matched by the unqualified name of their type
+    /// when they are registered, and are created with their full type path as symbol. A symbol
+    /// such as `Position` or `other::Position` would therefore clash with another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut pos = world.component::<Position>();
+    /// pos.set_symbol("mycrate.Position");
+    ///
+    /// assert_eq!(pos.symbol(), "mycrate.Position");
+    /// assert_eq!(world.lookup_symbol("mycrate.Position"), pos.id());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::symbol()`]
+    /// * [`World::lookup_symbol()`]
+    /// * C API: `ecs_set_symbol`
+    #[doc(alias = "ecs_set_symbol")]
+    pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
+        // Rust type names never contain a `.`, so such a symbol can't clash with the
+        // name or path of a type that is registered later
+        assert!(
+            symbol.contains('.') && !symbol.contains("::"),
+            "{}: symbol `{}` must be separated by `.` to not clash with the name of a type",
+            FlecsErrorCode::InvalidParameter,
+            symbol
+        );
+
+        let world = self.world.world_ptr_mut();
+        let symbol_c = compact_str::format_compact!("{}\0", symbol);
+        let existing = unsafe {
+            sys::ecs_lookup_symbol(world, symbol_c.as_ptr() as *const c_char, false, false)
+        };
+        assert!(
+            existing == 0 || existing == *self.id,
+            "{}: symbol `{}` is already used by entity {}",
+            FlecsErrorCode::AlreadyDefined,
+            symbol,
+            existing
+        );
+
+        unsafe {
+            sys::ecs_set_symbol(world, *self.id, symbol_c.as_ptr() as *const c_char);
+        }
+        self
+    }
+
+    /// Add the [`Exclusive`](flecs::Exclusive) trait to the component.
+    ///
+    /// An exclusive relationship can only have a single target per entity. Adding a pair
+    /// with a different target replaces the existing pair.
+    ///
+    /// Traits should be added before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn exclusive(&mut self) -> &mut Self {
+        self.entity.add_trait::<flecs::Exclusive>();
+        self
+    }
+
+    /// Add the [`Symmetric`](flecs::Symmetric) trait to the component.
+    ///
+    /// Adding `(R, B)` to `A` for a symmetric relationship `R` also adds `(R, A)` to `B`.
+    ///
+    /// Traits should be added before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn symmetric(&mut self) -> &mut Self {
+        self.entity.add_trait::<flecs::Symmetric>();
+        self
+    }
+
+    /// Add the [`Transitive`](flecs::Transitive) trait to the component.
+    ///
+    /// If `A` has `(R, B)` and `B` has `(R, C)` for a transitive relationship `R`, queries
+    /// for `(R, C)` also match `A`.
+    ///
+    /// Traits should be added before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn transitive(&mut self) -> &mut Self {
+        self.entity.add_trait::<flecs::Transitive>();
+        self
+    }
+
+    /// Add the [`With`](flecs::With) trait to the component, with `T` as target.
+    ///
+    /// Adding the component to an entity also adds `T`. Removing the component does not
+    /// remove `T`.
+    ///
+    /// Traits should be added before the component is used.
+    ///
+    /// This shadows [`EntityView::with()`] for component handles. Use
+    /// [`UntypedComponent::as_entity()`] to access it.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `W` - The component to add together with this component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Velocity>().with::<Position>();
+    ///
+    /// let e = world.entity().add::<Velocity>();
+    /// assert!(e.has::<Position>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn with<W: ComponentId>(&mut self) -> &mut Self {
+        self.entity.add_trait::<(flecs::With, W)>();
+        self
+    }
+
+    /// Add a tag to the component entity.
+    ///
+    /// Unlike [`EntityView::add_trait()`], this is not limited to the built-in flecs
+    /// traits, so it can attach arbitrary metadata to a component. Pairs are accepted
+    /// as long as the relationship is a tag. Components with the
+    /// tag can be found by querying for it, as component entities are regular entities.
+    ///
+    /// This shadows [`EntityView::add_trait()`] for component handles. Use
+    /// [`UntypedComponent::as_entity()`] to access it.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Tr` - The tag or pair to add to the component entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Serializable;
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().add_trait::<Serializable>();
+    ///
+    /// assert!(world.component::<Position>().has::<Serializable>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn add_trait<Tr>(&mut self) -> &mut Self
+    where
+        Tr: ComponentOrPairId,
+    {
+        const {
+            if !Tr::First::IS_TAG {
+                panic!("Component traits must be tags / zero sized types");
+            }
+        }
+        let world = self.entity.world;
+        unsafe { self.entity.add_id_unchecked(Tr::get_id(world)) };
+        self
+    }
+
+    /// Set the instantiation policy of the component to [`Override`](flecs::Override).
+    ///
+    /// Instances of a prefab get their own copy of the component, initialized with the value
+    /// of the prefab. This is the default policy.
+    ///
+    /// This adds the `(OnInstantiate, Override)` pair to the component. Policies should be set
+    /// before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`Component::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn on_instantiate_override(&mut self) -> &mut Self {
+        self.entity
+            .add_trait::<(flecs::OnInstantiate, flecs::Override)>();
+        self
+    }
+
+    /// Set the instantiation policy of the component to [`Inherit`](flecs::Inherit).
+    ///
+    /// Instances of a prefab do not get a copy of the component, but share the component of
+    /// the prefab. The instance has the component, but does not own it.
+    ///
+    /// This adds the `(OnInstantiate, Inherit)` pair to the component. Policies should be set
+    /// before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`Component::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn on_instantiate_inherit(&mut self) -> &mut Self {
+        self.entity
+            .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+        self
+    }
+
+    /// Set the instantiation policy of the component to [`DontInherit`](flecs::DontInherit).
+    ///
+    /// The component is not copied to or shared with instances of a prefab, so instances do
+    /// not have the component at all.
+    ///
+    /// This adds the `(OnInstantiate, DontInherit)` pair to the component. Policies should be set
+    /// before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`Component::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn on_instantiate_dont_inherit(&mut self) -> &mut Self {
+        self.entity
+            .add_trait::<(flecs::OnInstantiate, flecs::DontInherit)>();
+        self
+    }
+
+    /// Make the component inherit from the `Base` component, by adding `(IsA, Base)`.
+    ///
+    /// Queries for `Base` also match entities that have the component.
+    ///
+    /// This shadows [`EntityView::is_a()`] for component handles, so that calls can be chained
+    /// with other component methods. Use [`UntypedComponent::as_entity()`] to access it.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Base` - The component to inherit from.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::is_a()`]
+    /// * C++ API: `entity_builder::is_a`
+    #[doc(alias = "entity_builder::is_a")]
+    pub fn is_a<Base: ComponentId>(&mut self) -> &mut Self {
+        self.entity.is_a::<Base>();
+        self
+    }
+
+    /// Add the [`Sparse`](flecs::Sparse) trait to the component.
+    ///
+    /// Sparse components are not stored in table columns, but in a separate storage
+    /// that is indexed by entity. This is useful for large components that are only
+    /// present on few entities, or components that need stable pointers.
+    ///
+    /// Query results with sparse fields contain a single entity, so fields can be
+    /// accessed the same way as for regular components.
+    ///
+    /// Traits should be added before the component is used.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn sparse(&mut self) -> &mut Self {
+        self.entity.add_trait::<flecs::Sparse>();
+        self
+    }
+
+    /// Register the reflection data of the component.
+    ///
+    /// Non-generic components that derive `Meta` register their members when they
+    /// are registered with a world, so this only needs to be called for generic
+    /// components. Calling this for a component that already has members does nothing.
+    ///
+    /// # See also
+    ///
+    /// * [`MetaComponent`]
+    #[cfg(feature = "flecs_meta")]
+    pub fn meta(&self) -> &Self
+    where
+        T: MetaComponent,
+    {
+        if !self.entity.has::<flecs::meta::StructT>() {
+            T::meta(self.base);
+        }
+        self
+    }
+
+    /// Register the component as an opaque type.
+    ///
+    /// Opaque types have a layout that can't be described with reflection members, such
+    /// as a `String` or a `HashMap`. Instead the returned builder is used to provide a
+    /// callback that serializes the value as the type `U`, and callbacks that assign
+    /// deserialized values back to the component. The reflection data is registered when
+    /// the builder is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `U` - The type the component is serialized as.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::ffi::{c_char, CStr};
+    ///
+    /// #[derive(Component)]
+    /// struct Label(String);
+    ///
+    /// extern "C" fn serialize(ser: &Serializer, label: &Label) -> i32 {
+    ///     ser.value_str(&label.0)
+    /// }
+    ///
+    /// extern "C" fn assign_string(label: &mut Label, value: *const c_char) {
+    ///     label.0 = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Label>()
+    ///     .opaque::<flecs::meta::String>()
+    ///     .serialize(serialize)
+    ///     .assign_string(assign_string);
+    ///
+    /// assert!(world.component::<Label>().has::<flecs::meta::Opaque>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Opaque`]
+    /// * [`Serializer`]
+    /// * C++ API: `component::opaque`
+    #[doc(alias = "component::opaque")]
+    #[cfg(feature = "flecs_meta")]
+    pub fn opaque<U: ComponentId>(&self) -> Opaque<'a, T> {
+        let world = self.world();
+        Opaque::new(world, U::id(world))
+    }
+
     /// Function to free the on add hook.
     unsafe extern "C" fn on_add_drop<Func>(func: *mut c_void)
     where
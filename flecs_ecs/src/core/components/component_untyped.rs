@@ -1,6 +1,8 @@
 use std::ops::Deref;
 
 use crate::core::*;
+#[cfg(feature = "flecs_meta")]
+use crate::sys;
 
 /// Untyped component class.
 #[derive(Clone, Copy)]
@@ -46,7 +48,305 @@ impl<'a> UntypedComponent<'a> {
 }
 
 #[cfg(feature = "flecs_meta")]
-impl<'a> UntypedComponent<'a> {}
+impl<'a> UntypedComponent<'a> {
+    /// Add a member to the component's reflection metadata.
+    ///
+    /// Members are laid out in the order they are added.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - The type of the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member_unit()`]
+    /// * C++ API: `untyped_component::member`
+    #[doc(alias = "untyped_component::member")]
+    pub fn member<M: flecs::meta::MetaPrimitive>(&self, name: &str) -> &Self {
+        self.member_impl(M::ID, 0, name, 0)
+    }
+
+    /// Add a member with a unit to the component's reflection metadata.
+    ///
+    /// The unit is used by the explorer and JSON serializer. Builtin units
+    /// require the [`Units`](crate::addons::units::Units) module to be imported.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - The type of the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member.
+    /// * `unit` - The unit entity of the member.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::addons::units::Units;
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     speed: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.import::<Units>();
+    ///
+    /// world
+    ///     .component::<Velocity>()
+    ///     .member_unit::<f32>("speed", flecs::units::MetersPerSecond);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member()`]
+    /// * C++ API: `untyped_component::member`
+    #[doc(alias = "untyped_component::member")]
+    pub fn member_unit<M: flecs::meta::MetaPrimitive>(
+        &self,
+        name: &str,
+        unit: impl Into<Entity>,
+    ) -> &Self {
+        let unit = *unit.into();
+        ecs_assert!(
+            unit != 0,
+            FlecsErrorCode::InvalidParameter,
+            "unit is not registered, import the units module first"
+        );
+        self.member_impl(M::ID, unit, name, 0)
+    }
+
+    /// Add a member with a value range to the component's reflection metadata.
+    ///
+    /// The range describes the expected values of the member. It is included in the
+    /// type info of the component, so tools such as the explorer can use it to validate
+    /// values. Note that values outside of the range are not rejected when the component
+    /// is set or deserialized.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - The type of the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member.
+    /// * `min` - The minimum value of the member.
+    /// * `max` - The maximum value of the member.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health {
+    ///     hp: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Health>()
+    ///     .member_range::<f32>("hp", 0.0, 100.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member()`]
+    /// * C++ API: `untyped_component::range`
+    #[doc(alias = "untyped_component::range")]
+    pub fn member_range<M: flecs::meta::MetaPrimitive>(
+        &self,
+        name: &str,
+        min: f64,
+        max: f64,
+    ) -> &Self {
+        let member = self.member_entity(M::ID, 0, name, 0);
+        let ranges = sys::EcsMemberRanges {
+            value: sys::ecs_member_value_range_t { min, max },
+            warning: sys::ecs_member_value_range_t { min: 0.0, max: 0.0 },
+            error: sys::ecs_member_value_range_t { min: 0.0, max: 0.0 },
+        };
+        unsafe {
+            sys::ecs_set_id(
+                self.entity.world.world_ptr_mut(),
+                member,
+                flecs::meta::MemberRanges::ID,
+                std::mem::size_of::<sys::EcsMemberRanges>(),
+                &ranges as *const _ as *const std::ffi::c_void,
+            );
+        }
+        self
+    }
+
+    /// Add a member with an explicit offset to the component's reflection metadata.
+    ///
+    /// Members without an explicit offset are laid out using C layout rules, which
+    /// may not match the layout of a Rust struct. Members with an offset must be
+    /// added in order of increasing offset, starting with the member at offset `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_id` - The type of the member.
+    /// * `name` - The name of the member.
+    /// * `offset` - The offset of the member in the component, in bytes.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member()`]
+    /// * C++ API: `untyped_component::member`
+    #[doc(alias = "untyped_component::member")]
+    pub fn member_id(&self, type_id: impl Into<Entity>, name: &str, offset: usize) -> &Self {
+        self.member_impl(*type_id.into(), 0, name, offset as i32)
+    }
+
+    /// Add a constant to the component's reflection metadata.
+    ///
+    /// This turns the component into an enum type, if it isn't one already. The constant is
+    /// created as a child entity of the component, and stores its value in the
+    /// `(Constant, i32)` pair. Constant entities can be used as relationship targets, with the
+    /// component as relationship.
+    ///
+    /// The component must have the size of an `i32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the constant.
+    /// * `value` - The value of the constant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[repr(C)]
+    /// struct Color(i32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let color = world.component::<Color>();
+    /// color.constant("Red", 1).constant("Green", 2);
+    ///
+    /// let green = color.lookup("Green");
+    /// world.entity().add_id((color, green));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member()`]
+    /// * C++ API: `untyped_component::constant`
+    #[doc(alias = "untyped_component::constant")]
+    pub fn constant(&self, name: &str, value: i32) -> &Self {
+        self.constant_impl(flecs::meta::Enum::ID, flecs::meta::I32::ID, name, value)
+    }
+
+    /// Add a bitmask constant to the component.
+    ///
+    /// This turns the component into a bitmask type, which serializes combinations
+    /// of flags as a `|` separated list of constant names, such as `"Bacon|Lettuce"`.
+    /// The component should have the layout of a `u32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the constant.
+    /// * `value` - The value of the flag, typically a single bit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[repr(C)]
+    /// struct Toppings(u32);
+    ///
+    /// impl Toppings {
+    ///     const BACON: u32 = 1 << 0;
+    ///     const LETTUCE: u32 = 1 << 1;
+    ///     const TOMATO: u32 = 1 << 2;
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Toppings>()
+    ///     .bit("Bacon", Toppings::BACON)
+    ///     .bit("Lettuce", Toppings::LETTUCE)
+    ///     .bit("Tomato", Toppings::TOMATO);
+    ///
+    /// world.entity().set(Toppings(Toppings::BACON | Toppings::TOMATO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::constant()`]
+    /// * C++ API: `untyped_component::bit`
+    #[doc(alias = "untyped_component::bit")]
+    pub fn bit(&self, name: &str, value: u32) -> &Self {
+        self.constant_impl(flecs::meta::Bitmask::ID, flecs::meta::U32::ID, name, value)
+    }
+
+    fn constant_impl<T: Copy>(&self, kind: u64, value_type: u64, name: &str, value: T) -> &Self {
+        let world = self.entity.world.world_ptr_mut();
+        let name = compact_str::format_compact!("{}\0", name);
+        let desc = sys::ecs_entity_desc_t {
+            name: name.as_ptr() as *const _,
+            parent: *self.entity.id,
+            ..Default::default()
+        };
+        unsafe {
+            sys::ecs_add_id(world, *self.entity.id, kind);
+            let eid = sys::ecs_entity_init(world, &desc);
+            sys::ecs_set_id(
+                world,
+                eid,
+                ecs_pair(flecs::meta::Constant::ID, value_type),
+                std::mem::size_of::<T>(),
+                &value as *const T as *const std::ffi::c_void,
+            );
+        }
+        self
+    }
+
+    fn member_impl(&self, type_id: u64, unit: u64, name: &str, offset: i32) -> &Self {
+        self.member_entity(type_id, unit, name, offset);
+        self
+    }
+
+    fn member_entity(&self, type_id: u64, unit: u64, name: &str, offset: i32) -> u64 {
+        let world = self.entity.world;
+        let name = compact_str::format_compact!("{}\0", name);
+        let desc = sys::ecs_entity_desc_t {
+            name: name.as_ptr() as *const _,
+            parent: *self.entity.id,
+            ..Default::default()
+        };
+        let member = sys::EcsMember {
+            type_: type_id,
+            count: 0,
+            unit,
+            offset,
+        };
+        unsafe {
+            let eid = sys::ecs_entity_init(world.world_ptr_mut(), &desc);
+            sys::ecs_set_id(
+                world.world_ptr_mut(),
+                eid,
+                flecs::meta::Member::ID,
+                std::mem::size_of::<sys::EcsMember>(),
+                &member as *const _ as *const std::ffi::c_void,
+            );
+            eid
+        }
+    }
+}
 
 #[cfg(feature = "flecs_metrics")]
 impl<'a> UntypedComponent<'a> {}
@@ -6,6 +6,8 @@ mod component_binding;
 mod component_untyped;
 #[doc(hidden)]
 pub mod lifecycle_traits;
+#[cfg(feature = "flecs_meta")]
+mod opaque;
 
 pub use cached_ref::*;
 pub use component::*;
@@ -13,3 +15,5 @@ pub(crate) use component_binding::*;
 pub use component_untyped::*;
 #[doc(hidden)]
 pub use lifecycle_traits::*;
+#[cfg(feature = "flecs_meta")]
+pub use opaque::*;
@@ -0,0 +1,316 @@
+//! Reflection support for types that can't be described as a flecs struct.
+
+use std::{ffi::c_char, marker::PhantomData, os::raw::c_void};
+
+use crate::core::*;
+use crate::sys;
+
+/// Serializes the value of an opaque type.
+pub type SerializeFn<T> = extern "C" fn(&Serializer, &T) -> i32;
+/// Assigns a bool value to an opaque type.
+pub type AssignBoolFn<T> = extern "C" fn(&mut T, bool);
+/// Assigns a signed integer value to an opaque type.
+pub type AssignIntFn<T> = extern "C" fn(&mut T, i64);
+/// Assigns an unsigned integer value to an opaque type.
+pub type AssignUIntFn<T> = extern "C" fn(&mut T, u64);
+/// Assigns a floating point value to an opaque type.
+pub type AssignFloatFn<T> = extern "C" fn(&mut T, f64);
+/// Assigns a null terminated string value to an opaque type.
+pub type AssignStringFn<T> = extern "C" fn(&mut T, *const c_char);
+/// Assigns null to an opaque type.
+pub type AssignNullFn<T> = extern "C" fn(&mut T);
+/// Clears the elements of an opaque collection type.
+pub type ClearFn<T> = extern "C" fn(&mut T);
+/// Returns the number of elements of an opaque collection type.
+pub type CountFn<T> = extern "C" fn(&T) -> usize;
+/// Resizes an opaque collection type.
+pub type ResizeFn<T> = extern "C" fn(&mut T, usize);
+/// Ensures and returns a pointer to the element at an index of an opaque collection type.
+pub type EnsureElementFn<T> = extern "C" fn(&mut T, usize) -> *mut c_void;
+/// Ensures and returns a pointer to the member with a name of an opaque type.
+pub type EnsureMemberFn<T> = extern "C" fn(&mut T, *const c_char) -> *mut c_void;
+
+// The C signatures of the callbacks above. References and `Serializer` have the same
+// ABI as the pointers flecs passes, which is what makes the transmutes below sound.
+type CSerializeFn = unsafe extern "C" fn(*const sys::ecs_serializer_t, *const c_void) -> i32;
+type CAssignBoolFn = unsafe extern "C" fn(*mut c_void, bool);
+type CAssignIntFn = unsafe extern "C" fn(*mut c_void, i64);
+type CAssignUIntFn = unsafe extern "C" fn(*mut c_void, u64);
+type CAssignFloatFn = unsafe extern "C" fn(*mut c_void, f64);
+type CAssignStringFn = unsafe extern "C" fn(*mut c_void, *const c_char);
+type CAssignNullFn = unsafe extern "C" fn(*mut c_void);
+type CClearFn = unsafe extern "C" fn(*mut c_void);
+type CCountFn = unsafe extern "C" fn(*const c_void) -> usize;
+type CResizeFn = unsafe extern "C" fn(*mut c_void, usize);
+type CEnsureElementFn = unsafe extern "C" fn(*mut c_void, usize) -> *mut c_void;
+type CEnsureMemberFn = unsafe extern "C" fn(*mut c_void, *const c_char) -> *mut c_void;
+
+/// Serializer passed to the [`SerializeFn`] of an opaque type.
+///
+/// # See also
+///
+/// * C++ API: `serializer`
+#[doc(alias = "serializer")]
+#[repr(transparent)]
+pub struct Serializer {
+    ser: sys::ecs_serializer_t,
+}
+
+impl Serializer {
+    /// Get the world the value is serialized for.
+    pub fn world(&self) -> WorldRef<'_> {
+        unsafe { WorldRef::from_ptr(self.ser.world as *mut _) }
+    }
+
+    /// Serialize a value of the type with the provided id.
+    ///
+    /// # Safety
+    ///
+    /// `value` must point to a valid value of the type `type_id`.
+    ///
+    /// # Returns
+    ///
+    /// Zero on success, non-zero if serialization failed.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `serializer::value`
+    #[doc(alias = "serializer::value")]
+    pub unsafe fn value_id(&self, type_id: impl Into<Entity>, value: *const c_void) -> i32 {
+        let serialize = self.ser.value.expect("serializer has no value callback");
+        serialize(&self.ser, *type_id.into(), value)
+    }
+
+    /// Serialize a primitive value.
+    ///
+    /// # Returns
+    ///
+    /// Zero on success, non-zero if serialization failed.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `serializer::value`
+    #[doc(alias = "serializer::value")]
+    pub fn value<V: flecs::meta::MetaPrimitive>(&self, value: &V) -> i32 {
+        unsafe { self.value_id(V::ID, value as *const V as *const c_void) }
+    }
+
+    /// Serialize a string value.
+    ///
+    /// # Returns
+    ///
+    /// Zero on success, non-zero if serialization failed.
+    pub fn value_str(&self, value: &str) -> i32 {
+        let value = compact_str::format_compact!("{}\0", value);
+        let ptr = value.as_ptr() as *const c_char;
+        unsafe {
+            self.value_id(
+                flecs::meta::String::ID,
+                &ptr as *const *const c_char as *const c_void,
+            )
+        }
+    }
+
+    /// Serialize the name of the next member, for opaque types that map to a struct.
+    ///
+    /// # Returns
+    ///
+    /// Zero on success, non-zero if serialization failed.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `serializer::member`
+    #[doc(alias = "serializer::member")]
+    pub fn member(&self, name: &str) -> i32 {
+        let name = compact_str::format_compact!("{}\0", name);
+        let member = self.ser.member.expect("serializer has no member callback");
+        unsafe { member(&self.ser, name.as_ptr() as *const c_char) }
+    }
+}
+
+/// Builder for the reflection data of an opaque type.
+///
+/// An opaque type is a type with a layout that can't be described by the
+/// reflection framework, such as a `String` or a `HashMap`. Instead it provides
+/// a serialize callback that converts the value to a type the reflection framework
+/// does know about, and assign callbacks to convert a deserialized value back.
+///
+/// Returned by [`Component::opaque()`]. The reflection data is registered when the
+/// builder is dropped.
+///
+/// # See also
+///
+/// * C++ API: `opaque`
+#[doc(alias = "opaque")]
+pub struct Opaque<'a, T: ComponentId> {
+    world: WorldRef<'a>,
+    desc: sys::ecs_opaque_desc_t,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: ComponentId> Opaque<'a, T> {
+    pub(crate) fn new(world: impl WorldProvider<'a>, as_type: impl Into<Entity>) -> Self {
+        let world = world.world();
+        Self {
+            world,
+            desc: sys::ecs_opaque_desc_t {
+                entity: T::id(world),
+                type_: sys::EcsOpaque {
+                    as_type: *as_type.into(),
+                    ..Default::default()
+                },
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set the serialize callback.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::serialize`
+    #[doc(alias = "opaque::serialize")]
+    pub fn serialize(&mut self, func: SerializeFn<T>) -> &mut Self {
+        self.desc.type_.serialize =
+            Some(unsafe { std::mem::transmute::<SerializeFn<T>, CSerializeFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns a bool value.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_bool`
+    #[doc(alias = "opaque::assign_bool")]
+    pub fn assign_bool(&mut self, func: AssignBoolFn<T>) -> &mut Self {
+        self.desc.type_.assign_bool =
+            Some(unsafe { std::mem::transmute::<AssignBoolFn<T>, CAssignBoolFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns a signed integer value.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_int`
+    #[doc(alias = "opaque::assign_int")]
+    pub fn assign_int(&mut self, func: AssignIntFn<T>) -> &mut Self {
+        self.desc.type_.assign_int =
+            Some(unsafe { std::mem::transmute::<AssignIntFn<T>, CAssignIntFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns an unsigned integer value.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_uint`
+    #[doc(alias = "opaque::assign_uint")]
+    pub fn assign_uint(&mut self, func: AssignUIntFn<T>) -> &mut Self {
+        self.desc.type_.assign_uint =
+            Some(unsafe { std::mem::transmute::<AssignUIntFn<T>, CAssignUIntFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns a floating point value.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_float`
+    #[doc(alias = "opaque::assign_float")]
+    pub fn assign_float(&mut self, func: AssignFloatFn<T>) -> &mut Self {
+        self.desc.type_.assign_float =
+            Some(unsafe { std::mem::transmute::<AssignFloatFn<T>, CAssignFloatFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns a string value.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_string`
+    #[doc(alias = "opaque::assign_string")]
+    pub fn assign_string(&mut self, func: AssignStringFn<T>) -> &mut Self {
+        self.desc.type_.assign_string =
+            Some(unsafe { std::mem::transmute::<AssignStringFn<T>, CAssignStringFn>(func) });
+        self
+    }
+
+    /// Set the callback that assigns null.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::assign_null`
+    #[doc(alias = "opaque::assign_null")]
+    pub fn assign_null(&mut self, func: AssignNullFn<T>) -> &mut Self {
+        self.desc.type_.assign_null =
+            Some(unsafe { std::mem::transmute::<AssignNullFn<T>, CAssignNullFn>(func) });
+        self
+    }
+
+    /// Set the callback that clears a collection.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::clear`
+    #[doc(alias = "opaque::clear")]
+    pub fn clear(&mut self, func: ClearFn<T>) -> &mut Self {
+        self.desc.type_.clear = Some(unsafe { std::mem::transmute::<ClearFn<T>, CClearFn>(func) });
+        self
+    }
+
+    /// Set the callback that returns the number of elements in a collection.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::count`
+    #[doc(alias = "opaque::count")]
+    pub fn count(&mut self, func: CountFn<T>) -> &mut Self {
+        self.desc.type_.count = Some(unsafe { std::mem::transmute::<CountFn<T>, CCountFn>(func) });
+        self
+    }
+
+    /// Set the callback that resizes a collection.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::resize`
+    #[doc(alias = "opaque::resize")]
+    pub fn resize(&mut self, func: ResizeFn<T>) -> &mut Self {
+        self.desc.type_.resize =
+            Some(unsafe { std::mem::transmute::<ResizeFn<T>, CResizeFn>(func) });
+        self
+    }
+
+    /// Set the callback that ensures and returns an element of a collection.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::ensure_element`
+    #[doc(alias = "opaque::ensure_element")]
+    pub fn ensure_element(&mut self, func: EnsureElementFn<T>) -> &mut Self {
+        self.desc.type_.ensure_element =
+            Some(unsafe { std::mem::transmute::<EnsureElementFn<T>, CEnsureElementFn>(func) });
+        self
+    }
+
+    /// Set the callback that ensures and returns a member.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `opaque::ensure_member`
+    #[doc(alias = "opaque::ensure_member")]
+    pub fn ensure_member(&mut self, func: EnsureMemberFn<T>) -> &mut Self {
+        self.desc.type_.ensure_member =
+            Some(unsafe { std::mem::transmute::<EnsureMemberFn<T>, CEnsureMemberFn>(func) });
+        self
+    }
+}
+
+impl<T: ComponentId> Drop for Opaque<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::ecs_opaque_init(self.world.world_ptr_mut(), &self.desc);
+        }
+    }
+}
@@ -229,6 +229,37 @@ impl<'a> EntityView<'a> {
         self.get_name_cstr().and_then(|s| s.to_str().ok())
     }
 
+    /// Returns the entity name, or `#<id>` if the entity has no name.
+    ///
+    /// This matches how flecs formats unnamed entities in paths, so the result is
+    /// always usable in log messages. Only the index of the id is used, without the
+    /// generation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let bob = world.entity_named("Bob");
+    /// assert_eq!(bob.display_name(), "Bob");
+    ///
+    /// let e = world.entity();
+    /// assert_eq!(e.display_name(), format!("#{}", *e.id() as u32));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_name()`]
+    /// * [`EntityView::path()`]
+    pub fn display_name(self) -> String {
+        match self.get_name() {
+            Some(name) => name.to_string(),
+            None => format!("#{}", *self.id as u32),
+        }
+    }
+
     /// Returns the entity name as a `CStr`.
     ///
     /// if the entity has no name, this will return an empty string
@@ -1225,6 +1256,48 @@ impl<'a> EntityView<'a> {
         unsafe { sys::ecs_get_mut_id(self.world.world_ptr(), *self.id(), *id.into()) }
     }
 
+    /// Get a copy of the raw bytes of a component value or pair.
+    ///
+    /// This is intended for generic tooling, such as editors or serializers, that does not know
+    /// the Rust type of the component. The length of the bytes is the size of the component
+    /// as registered in its type info. The bytes are copied out of the table storage, so they
+    /// stay valid when the storage is reallocated, for example by setting the component on
+    /// another entity in the same table.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The component or pair to get.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the component value, or `None` if the entity does not have the id or the id
+    /// does not store data.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_raw()`]
+    /// * [`EntityView::get_untyped()`]
+    /// * [`IdView::size()`]
+    /// * C API: `ecs_get_id`
+    #[doc(alias = "ecs_get_id")]
+    pub fn get_raw(self, id: impl IntoId) -> Option<Vec<u8>> {
+        let world = self.world.world_ptr();
+        let id = *id.into();
+        unsafe {
+            let type_info = sys::ecs_get_type_info(world, id);
+            if type_info.is_null() || (*type_info).size == 0 {
+                return None;
+            }
+
+            let ptr = sys::ecs_get_id(world, *self.id, id) as *const u8;
+            if ptr.is_null() {
+                None
+            } else {
+                Some(std::slice::from_raw_parts(ptr, (*type_info).size as usize).to_vec())
+            }
+        }
+    }
+
     /// Get target for a given pair.
     ///
     /// This operation returns the target for a given pair. The optional
@@ -1455,7 +1528,7 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// * The parent of the entity.
+    /// * The parent of the entity, or `None` if the entity is a root entity.
     ///
     /// # See also
     ///
@@ -1644,10 +1717,12 @@ impl<'a> EntityView<'a> {
     /// # Returns
     ///
     /// True if the entity has or inherits the provided component, false otherwise.
+    /// Use [`EntityView::owns()`] to only check components stored on the entity itself.
     ///
     /// # See also
     ///
     /// * [`EntityView::has_id()`]
+    /// * [`EntityView::owns()`]
     /// * C++ API: `entity_view::has`
     #[doc(alias = "entity_view::has")]
     pub fn has<T: ComponentOrPairId>(self) -> bool {
@@ -1694,6 +1769,56 @@ impl<'a> EntityView<'a> {
         self.has_id((component_id, enum_constant_entity_id))
     }
 
+    /// Get the enum constant the entity currently has for the enum type.
+    ///
+    /// The target of the `(T, *)` pair is decoded back into the matching variant.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The enum type.
+    ///
+    /// # Returns
+    ///
+    /// The active enum constant, or `None` if the entity has no `(T, *)` pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// #[repr(C)]
+    /// enum Color {
+    ///     Red,
+    ///     Green,
+    ///     Blue,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// assert_eq!(e.get_enum::<Color>(), None);
+    ///
+    /// e.add_enum(Color::Green);
+    /// assert_eq!(e.get_enum::<Color>(), Some(Color::Green));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_enum()`]
+    /// * [`EntityView::has_enum()`]
+    /// * C++ API: `entity_view::get_constant`
+    #[doc(alias = "entity_view::get_constant")]
+    pub fn get_enum<T>(self) -> Option<T>
+    where
+        T: ComponentId + ComponentType<Enum> + EnumComponentInfo,
+    {
+        let target = self.target::<T>(0)?;
+        // Safety: the enum is registered because of the previous T::id call in target
+        T::iter()
+            .find(|variant| unsafe { variant.id_variant_unchecked(self.world) }.id() == target.id())
+    }
+
     // this is pub(crate) because it's used for development purposes only
     pub(crate) fn has_enum_id<T>(self, enum_id: impl Into<Entity>, constant: T) -> bool
     where
@@ -1725,6 +1850,27 @@ impl<'a> EntityView<'a> {
         self.has_id((First::id(self.world), second.into()))
     }
 
+    /// Check if entity has any pair with the provided relationship.
+    /// This checks for `(First, *)`, regardless of the target.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The relationship of the pair.
+    ///
+    /// # Returns
+    ///
+    /// True if the entity has at least one `(First, *)` pair, false otherwise.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::has_first()`]
+    /// * [`EntityView::remove_pair_wildcard()`]
+    /// * C++ API: `entity_view::has`
+    #[doc(alias = "entity_view::has")]
+    pub fn has_pair_wildcard<First: ComponentId>(self) -> bool {
+        self.has_first::<First>(ECS_WILDCARD)
+    }
+
     /// Check if entity has the provided pair.
     ///
     /// # Type Parameters
@@ -1804,8 +1950,32 @@ impl<'a> EntityView<'a> {
     ///
     /// - `true` if the entity owns the provided component, `false` otherwise.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().on_instantiate_inherit();
+    ///
+    /// let base = world.prefab().set(Position { x: 1.0, y: 2.0 });
+    /// let instance = world.entity().is_a_id(base);
+    ///
+    /// // the component is inherited from the base, not stored on the instance
+    /// assert!(instance.has::<Position>());
+    /// assert!(!instance.owns::<Position>());
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`EntityView::has()`]
+    /// * [`EntityView::owns_id()`]
     /// * C++ API: `entity_view::owns`
     #[doc(alias = "entity_view::owns")]
     pub fn owns<T: ComponentOrPairId>(self) -> bool {
@@ -2116,6 +2286,10 @@ impl<'a> EntityView<'a> {
 
     /// Emit event with an immutable payload for entity.
     ///
+    /// The event is emitted for the `flecs::Any` id, which notifies entity observers
+    /// (see [`EntityView::observe_payload()`]). To notify observers that filter on
+    /// components, emit the event with [`World::event()`] and add those components.
+    ///
     /// # Type Parameters
     ///
     /// * T - the event type to emit.
@@ -44,6 +44,7 @@ impl<'a> EntityView<'a> {
 
         Self::check_add_id_validity(world, id);
 
+        check_table_not_locked(world, *self.id, id, true);
         unsafe { sys::ecs_add_id(world, *self.id, id) }
         self
     }
@@ -52,6 +53,7 @@ impl<'a> EntityView<'a> {
         let id = *id.into();
         let world = self.world.world_ptr_mut();
 
+        check_table_not_locked(world, *self.id, id, true);
         unsafe { sys::ecs_add_id(world, *self.id, id) }
         self
     }
@@ -95,9 +97,49 @@ impl<'a> EntityView<'a> {
     /// Override a component on an entity.
     /// This is useful if you want to override a component that is inherited by a prefab on a per entity basis
     ///
+    /// The entity gets its own copy of the component, initialized with the value of the base
+    /// it inherits from. Queries match the component on the entity itself afterwards, so
+    /// [`TableIter::is_self()`] returns `true` for the field. Changing the copy does not change
+    /// the base.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T`: The component or pair to override.
+    ///
     /// # Panics
     ///
     /// Caller must ensure the entity has the component to override.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Position>()
+    ///     .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+    ///
+    /// let base = world.prefab().set(Position { x: 1, y: 2 });
+    /// let inst = world.entity().is_a_id(base);
+    /// assert!(!inst.owns::<Position>());
+    ///
+    /// inst.override_type::<Position>();
+    /// assert!(inst.owns::<Position>());
+    /// inst.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 2)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::auto_override()`]
+    #[doc(alias = "override_component")]
     pub fn override_type<T>(self) -> Self
     where
         T: ComponentOrPairId,
@@ -423,7 +465,10 @@ impl<'a> EntityView<'a> {
     /// * C++ API: `entity_builder::remove`
     #[doc(alias = "entity_builder::remove")]
     pub fn remove_id(self, id: impl IntoId) -> Self {
-        unsafe { sys::ecs_remove_id(self.world.world_ptr_mut(), *self.id, *id.into()) }
+        let world = self.world.world_ptr_mut();
+        let id = *id.into();
+        check_table_not_locked(world, *self.id, id, false);
+        unsafe { sys::ecs_remove_id(world, *self.id, id) }
         self
     }
 
@@ -513,6 +558,22 @@ impl<'a> EntityView<'a> {
         self.remove_id((first.into(), Second::id(world)))
     }
 
+    /// Removes all pairs with the given relationship.
+    /// This operation removes `(First, *)` from the entity, regardless of the target.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First`: The relationship of the pairs to remove.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::remove_first()`]
+    /// * C++ API: `entity_builder::remove`
+    #[doc(alias = "entity_builder::remove")]
+    pub fn remove_pair_wildcard<First: ComponentId>(self) -> Self {
+        self.remove_first::<First>(ECS_WILDCARD)
+    }
+
     /// Shortcut for `add((flecs::IsA, id))`.
     ///
     /// # Arguments
@@ -529,6 +590,10 @@ impl<'a> EntityView<'a> {
 
     /// Shortcut for `add_id((flecs::IsA::ID, entity))`.
     ///
+    /// When called on a component, this makes the component inherit from `T`,
+    /// so that queries for `T` also match entities that have the component:
+    /// `world.component::<Sphere>().is_a::<Shape>()`.
+    ///
     /// # Type Parameters
     ///
     /// * `T`: the type associated with the entity.
@@ -544,12 +609,31 @@ impl<'a> EntityView<'a> {
 
     /// Shortcut for `add_id((flecs::ChildOf::ID, entity))`.
     ///
+    /// Makes the entity a child of `parent`, which can be chained while building the entity.
+    /// A name set on the entity is scoped to the parent.
+    ///
     /// # Arguments
     ///
-    /// * `second`: The second element of the pair.
+    /// * `parent`: The parent entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity_named("Parent");
+    /// let child = world.entity().child_of_id(parent).set_name("Child");
+    ///
+    /// assert_eq!(child.parent(), Some(parent));
+    /// assert_eq!(child.path().unwrap(), "::Parent::Child");
+    /// ```
     ///
     /// # See also
     ///
+    /// * [`EntityView::child_of()`]
+    /// * [`EntityView::parent()`]
     /// * C++ API: `entity_builder::child_of`
     #[doc(alias = "entity_builder::child_of")]
     pub fn child_of_id(self, parent: impl Into<Entity>) -> Self {
@@ -785,6 +869,7 @@ impl<'a> EntityView<'a> {
     /// # See also
     ///
     /// * C++ API: `entity_builder::set_auto_override`
+    #[doc(alias = "entity_builder::set_auto_override")]
     pub fn set_pair_override<First, Second>(
         self,
         data: <(First, Second) as ComponentOrPairId>::CastType,
@@ -867,6 +952,116 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Sets multiple components on the entity at once.
+    ///
+    /// The components are set inside a single defer block, so the entity
+    /// moves directly to the table with all components instead of moving
+    /// once per component. Components that don't implement `Default` and
+    /// are not yet on the entity are emplaced, which is not batched.
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - A tuple of the components to set on the entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set_many((Position { x: 10.0, y: 20.0 }, Velocity { x: 1.0, y: 2.0 }));
+    ///
+    /// assert!(e.has::<Position>());
+    /// assert!(e.has::<Velocity>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    pub fn set_many<T: SetTuple>(self, components: T) -> Self {
+        self.world.defer_begin();
+        components.set_components(self);
+        self.world.defer_end();
+        self
+    }
+
+    /// Initializes a component of type `T` in place on the entity.
+    ///
+    /// If the entity does not have the component yet it is default constructed
+    /// in the component storage, otherwise the existing value is used. The
+    /// closure then initializes the value, after which the component is marked
+    /// as modified. This avoids constructing large components on the stack and
+    /// moving them into storage.
+    ///
+    /// While the world is deferred the component storage is not available, in
+    /// which case the value is constructed and set as with [`EntityView::set()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The closure that initializes the component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().insert(|p: &mut Position| {
+    ///     p.x = 1.0;
+    ///     p.y = 2.0;
+    /// });
+    ///
+    /// e.get::<&Position>(|p| assert_eq!(p.x, 1.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    /// * C++ API: `entity_builder::insert`
+    #[doc(alias = "entity_builder::insert")]
+    pub fn insert<T: ComponentId + DataComponent + Default>(
+        self,
+        init: impl FnOnce(&mut T),
+    ) -> Self {
+        let world = self.world.world_ptr_mut();
+        let id = T::id(self.world);
+
+        if unsafe { sys::ecs_is_deferred(world) } {
+            let mut value = T::default();
+            init(&mut value);
+            set_helper(world, *self.id, value, id);
+        } else {
+            unsafe {
+                let ptr = sys::ecs_ensure_id(world, *self.id, id) as *mut T;
+                init(&mut *ptr);
+                sys::ecs_modified_id(world, *self.id, id);
+            }
+        }
+        self
+    }
+
     /// Sets the data of the specified id. Can be a pair or Component.
     ///
     /// # Safety
@@ -900,8 +1095,32 @@ impl<'a> EntityView<'a> {
     ///
     /// If the entity did not yet have the pair, it will be added, otherwise overridden.
     ///
+    /// # Example
+    ///
+    /// When the relationship is a tag, the pair stores the data of the target:
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Eats;
+    ///
+    /// #[derive(Component)]
+    /// struct Food {
+    ///     calories: u32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set_pair::<Eats, Food>(Food { calories: 10 });
+    ///
+    /// e.get::<&(Eats, Food)>(|food| assert_eq!(food.calories, 10));
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`EntityView::set_first()`]
+    /// * [`EntityView::set_second()`]
     /// * C++ API: `entity_builder::set`
     #[doc(alias = "entity_builder::set")]
     pub fn set_pair<First, Second>(
@@ -1012,6 +1231,9 @@ impl<'a> EntityView<'a> {
 
     /// Sets a pointer to a component of an entity with a given component ID and size.
     ///
+    /// The `size` bytes behind `ptr` are copied into the component storage, which makes this
+    /// useful for bridging with component data owned by C code.
+    ///
     /// # Safety
     /// Caller must ensure that `ptr` points to data that can be accessed as the type associated with `id`
     ///
@@ -1022,8 +1244,40 @@ impl<'a> EntityView<'a> {
     /// * `size` - The size of the component.
     /// * `ptr` - A pointer to the component.
     ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the id does not store data or if `size` does not match the
+    /// size of the component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[repr(C)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let position = world.component::<Position>();
+    ///
+    /// let data: [i32; 2] = [10, 20];
+    /// let e = unsafe {
+    ///     world
+    ///         .entity()
+    ///         .set_ptr_w_size(position, size_of_val(&data), data.as_ptr() as *const _)
+    /// };
+    ///
+    /// e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 20)));
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`EntityView::set_ptr()`]
+    /// * [`EntityView::set_raw()`]
     /// * C++ API: `entity_builder::set_ptr`
     #[doc(alias = "entity_builder::set_ptr")]
     pub unsafe fn set_ptr_w_size(
@@ -1032,7 +1286,28 @@ impl<'a> EntityView<'a> {
         size: usize,
         ptr: *const c_void,
     ) -> Self {
-        sys::ecs_set_id(self.world.world_ptr_mut(), *self.id, *id.into(), size, ptr);
+        let world = self.world.world_ptr_mut();
+        let id = *id.into();
+
+        #[cfg(any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"))]
+        {
+            let type_info = unsafe { sys::ecs_get_type_info(world, id) };
+            ecs_assert!(
+                !type_info.is_null(),
+                FlecsErrorCode::InvalidParameter,
+                "id {} does not store data",
+                id
+            );
+            ecs_assert!(
+                unsafe { (*type_info).size } as usize == size,
+                FlecsErrorCode::InvalidParameter,
+                "expected {} bytes, got {}",
+                unsafe { (*type_info).size },
+                size
+            );
+        }
+
+        sys::ecs_set_id(world, *self.id, id, size, ptr);
         self
     }
 
@@ -1071,6 +1346,56 @@ impl<'a> EntityView<'a> {
         self.set_ptr_w_size(id, unsafe { (*cptr).size } as usize, ptr)
     }
 
+    /// Sets a component value or pair from its raw bytes.
+    ///
+    /// This is the counterpart of [`EntityView::get_raw()`] for generic tooling that does not
+    /// know the Rust type of the component. The bytes are copied into the component storage with
+    /// the copy hook of the component, if it has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The component or pair to set.
+    /// * `data`: The bytes of the component value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id does not store data, or if the length of `data` does not match the size
+    /// of the component.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_raw()`]
+    /// * [`EntityView::set_ptr_w_size()`]
+    /// * [`IdView::size()`]
+    /// * C API: `ecs_set_id`
+    #[doc(alias = "ecs_set_id")]
+    pub fn set_raw(self, id: impl IntoId, data: &[u8]) -> Self {
+        let world = self.world.world_ptr_mut();
+        let id = *id.into();
+        let type_info = unsafe { sys::ecs_get_type_info(world, id) };
+
+        assert!(
+            !type_info.is_null(),
+            "{}: id {} does not store data",
+            FlecsErrorCode::InvalidParameter,
+            id
+        );
+
+        let size = unsafe { (*type_info).size } as usize;
+        assert!(
+            data.len() == size,
+            "{}: expected {} bytes, got {}",
+            FlecsErrorCode::InvalidParameter,
+            size,
+            data.len()
+        );
+
+        unsafe {
+            sys::ecs_set_id(world, *self.id, id, size, data.as_ptr() as *const c_void);
+        }
+        self
+    }
+
     /// Sets the name of the entity.
     ///
     /// # Arguments
@@ -1104,12 +1429,32 @@ impl<'a> EntityView<'a> {
 
     /// Sets the alias name of the entity.
     ///
+    /// An alias is a secondary name in the root scope. [`World::lookup()`] resolves both the
+    /// entity name and the alias, while [`EntityView::name()`] keeps returning the name.
+    /// An entity has at most one alias; setting a new one replaces the previous alias.
+    ///
     /// # Arguments
     ///
     /// * `name` - A string slice that holds the alias name to be set.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity_named("parent::child").set_alias("kid");
+    ///
+    /// assert_eq!(world.lookup("parent::child"), e);
+    /// assert_eq!(world.lookup("kid"), e);
+    /// assert_eq!(e.name(), "child");
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`World::lookup()`]
+    /// * [`World::set_alias_entity()`]
     /// * C++ API: `entity_builder::set_alias`
     #[doc(alias = "entity_builder::set_alias")]
     pub fn set_alias(self, name: &str) -> Self {
@@ -1406,12 +1751,17 @@ impl<'a> EntityView<'a> {
     /// * [`World::modified()`]
     /// * C++ API: `entity::modified`
     #[doc(alias = "entity::modified")]
-    pub fn modified_id(self, id: impl IntoId) {
-        unsafe { sys::ecs_modified_id(self.world.world_ptr_mut(), *self.id, *id.into()) }
+    pub fn modified_id(self, id: impl IntoId) -> Self {
+        unsafe { sys::ecs_modified_id(self.world.world_ptr_mut(), *self.id, *id.into()) };
+        self
     }
 
     /// Signal that component was modified.
     ///
+    /// Call this after writing component data through a pointer obtained from
+    /// [`EntityView::get_untyped_mut()`], so that `OnSet` observers run and
+    /// change detection picks up the write.
+    ///
     /// # Type Parameters
     ///
     /// * `T` - The type of the component that was modified.
@@ -1423,7 +1773,7 @@ impl<'a> EntityView<'a> {
     /// * [`World::modified()`]
     /// * C++ API: `entity::modified`
     #[doc(alias = "entity::modified")]
-    pub fn modified<T: ComponentOrPairId>(&self) {
+    pub fn modified<T: ComponentOrPairId>(self) -> Self {
         const {
             assert!(
                 std::mem::size_of::<T>() != 0,
@@ -1431,7 +1781,7 @@ impl<'a> EntityView<'a> {
             );
         };
 
-        self.modified_id(T::get_id(self.world));
+        self.modified_id(T::get_id(self.world))
     }
 
     /// Signal that the first part of a pair was modified.
@@ -1451,7 +1801,7 @@ impl<'a> EntityView<'a> {
     /// * [`World::modified()`]
     /// * C++ API: `entity::modified`
     #[doc(alias = "entity::modified")]
-    pub fn modified_first<First: ComponentId>(self, second: impl Into<Entity>) {
+    pub fn modified_first<First: ComponentId>(self, second: impl Into<Entity>) -> Self {
         ecs_assert!(
             std::mem::size_of::<First>() != 0,
             FlecsErrorCode::InvalidParameter,
@@ -1459,7 +1809,7 @@ impl<'a> EntityView<'a> {
             std::any::type_name::<First>()
         );
 
-        self.modified_id((First::id(self.world), second.into()));
+        self.modified_id((First::id(self.world), second.into()))
     }
 
     /// Get a reference to a component or pair.
@@ -1557,8 +1907,9 @@ impl<'a> EntityView<'a> {
     /// * C++ API: `entity::clear`
     #[doc(alias = "entity::clear")]
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    pub fn clear(&self) {
-        unsafe { sys::ecs_clear(self.world.world_ptr_mut(), *self.id) }
+    pub fn clear(self) -> Self {
+        unsafe { sys::ecs_clear(self.world.world_ptr_mut(), *self.id) };
+        self
     }
 
     /// Delete an entity.
@@ -288,6 +288,7 @@ pub mod meta {
     create_pre_registered_component!(Enum, ECS_ENUM);
     create_pre_registered_component!(Bitmask, ECS_BITMASK);
     create_pre_registered_component!(Member, ECS_MEMBER);
+    create_pre_registered_component!(MemberRanges, ECS_MEMBER_RANGES);
     create_pre_registered_component!(StructT, ECS_STRUCT);
     create_pre_registered_component!(Array, ECS_ARRAY);
     create_pre_registered_component!(Vector, ECS_VECTOR);
@@ -296,6 +297,146 @@ pub mod meta {
     create_pre_registered_component!(UnitPrefix, ECS_UNIT_PREFIX);
     create_pre_registered_component!(Constant, ECS_CONSTANT);
     create_pre_registered_component!(Quantity, ECS_QUANTITY);
+
+    /// Rust types that map onto a builtin meta primitive type, used to
+    /// describe the type of a component member.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::member()`]
+    pub trait MetaPrimitive {
+        /// The id of the builtin primitive type.
+        const ID: u64;
+    }
+
+    macro_rules! impl_meta_primitive {
+        ($type:ty, $const_name:ident) => {
+            impl MetaPrimitive for $type {
+                const ID: u64 = $const_name;
+            }
+        };
+    }
+
+    impl_meta_primitive!(bool, ECS_BOOL_T);
+    impl_meta_primitive!(u8, ECS_U8_T);
+    impl_meta_primitive!(u16, ECS_U16_T);
+    impl_meta_primitive!(u32, ECS_U32_T);
+    impl_meta_primitive!(u64, ECS_U64_T);
+    impl_meta_primitive!(usize, ECS_UPTR_T);
+    impl_meta_primitive!(i8, ECS_I8_T);
+    impl_meta_primitive!(i16, ECS_I16_T);
+    impl_meta_primitive!(i32, ECS_I32_T);
+    impl_meta_primitive!(i64, ECS_I64_T);
+    impl_meta_primitive!(isize, ECS_IPTR_T);
+    impl_meta_primitive!(f32, ECS_F32_T);
+    impl_meta_primitive!(f64, ECS_F64_T);
+    impl_meta_primitive!(crate::core::Entity, ECS_ENTITY_T);
+}
+
+// Unit entities, these are only valid after the units module is imported
+#[cfg(feature = "flecs_units")]
+pub mod units {
+    use super::*;
+
+    macro_rules! create_unit {
+        ($struct_name:ident, $static_name:ident) => {
+            #[derive(Debug, Default, Clone, Copy)]
+            pub struct $struct_name;
+
+            impl From<$struct_name> for Entity {
+                #[inline(always)]
+                fn from(_: $struct_name) -> Self {
+                    Entity::new(unsafe { sys::$static_name })
+                }
+            }
+        };
+    }
+
+    create_unit!(Duration, EcsDuration);
+    create_unit!(PicoSeconds, EcsPicoSeconds);
+    create_unit!(NanoSeconds, EcsNanoSeconds);
+    create_unit!(MicroSeconds, EcsMicroSeconds);
+    create_unit!(MilliSeconds, EcsMilliSeconds);
+    create_unit!(Seconds, EcsSeconds);
+    create_unit!(Minutes, EcsMinutes);
+    create_unit!(Hours, EcsHours);
+    create_unit!(Days, EcsDays);
+    create_unit!(Time, EcsTime);
+    create_unit!(Date, EcsDate);
+    create_unit!(Mass, EcsMass);
+    create_unit!(Grams, EcsGrams);
+    create_unit!(KiloGrams, EcsKiloGrams);
+    create_unit!(ElectricCurrent, EcsElectricCurrent);
+    create_unit!(Ampere, EcsAmpere);
+    create_unit!(Amount, EcsAmount);
+    create_unit!(Mole, EcsMole);
+    create_unit!(LuminousIntensity, EcsLuminousIntensity);
+    create_unit!(Candela, EcsCandela);
+    create_unit!(Force, EcsForce);
+    create_unit!(Newton, EcsNewton);
+    create_unit!(Length, EcsLength);
+    create_unit!(Meters, EcsMeters);
+    create_unit!(PicoMeters, EcsPicoMeters);
+    create_unit!(NanoMeters, EcsNanoMeters);
+    create_unit!(MicroMeters, EcsMicroMeters);
+    create_unit!(MilliMeters, EcsMilliMeters);
+    create_unit!(CentiMeters, EcsCentiMeters);
+    create_unit!(KiloMeters, EcsKiloMeters);
+    create_unit!(Miles, EcsMiles);
+    create_unit!(Pixels, EcsPixels);
+    create_unit!(Pressure, EcsPressure);
+    create_unit!(Pascal, EcsPascal);
+    create_unit!(Bar, EcsBar);
+    create_unit!(Speed, EcsSpeed);
+    create_unit!(MetersPerSecond, EcsMetersPerSecond);
+    create_unit!(KiloMetersPerSecond, EcsKiloMetersPerSecond);
+    create_unit!(KiloMetersPerHour, EcsKiloMetersPerHour);
+    create_unit!(MilesPerHour, EcsMilesPerHour);
+    create_unit!(Temperature, EcsTemperature);
+    create_unit!(Kelvin, EcsKelvin);
+    create_unit!(Celsius, EcsCelsius);
+    create_unit!(Fahrenheit, EcsFahrenheit);
+    create_unit!(Data, EcsData);
+    create_unit!(Bits, EcsBits);
+    create_unit!(KiloBits, EcsKiloBits);
+    create_unit!(MegaBits, EcsMegaBits);
+    create_unit!(GigaBits, EcsGigaBits);
+    create_unit!(Bytes, EcsBytes);
+    create_unit!(KiloBytes, EcsKiloBytes);
+    create_unit!(MegaBytes, EcsMegaBytes);
+    create_unit!(GigaBytes, EcsGigaBytes);
+    create_unit!(KibiBytes, EcsKibiBytes);
+    create_unit!(MebiBytes, EcsMebiBytes);
+    create_unit!(GibiBytes, EcsGibiBytes);
+    create_unit!(DataRate, EcsDataRate);
+    create_unit!(BitsPerSecond, EcsBitsPerSecond);
+    create_unit!(KiloBitsPerSecond, EcsKiloBitsPerSecond);
+    create_unit!(MegaBitsPerSecond, EcsMegaBitsPerSecond);
+    create_unit!(GigaBitsPerSecond, EcsGigaBitsPerSecond);
+    create_unit!(BytesPerSecond, EcsBytesPerSecond);
+    create_unit!(KiloBytesPerSecond, EcsKiloBytesPerSecond);
+    create_unit!(MegaBytesPerSecond, EcsMegaBytesPerSecond);
+    create_unit!(GigaBytesPerSecond, EcsGigaBytesPerSecond);
+    create_unit!(Angle, EcsAngle);
+    create_unit!(Radians, EcsRadians);
+    create_unit!(Degrees, EcsDegrees);
+    create_unit!(Frequency, EcsFrequency);
+    create_unit!(Hertz, EcsHertz);
+    create_unit!(KiloHertz, EcsKiloHertz);
+    create_unit!(MegaHertz, EcsMegaHertz);
+    create_unit!(GigaHertz, EcsGigaHertz);
+    create_unit!(Uri, EcsUri);
+    create_unit!(UriHyperlink, EcsUriHyperlink);
+    create_unit!(UriImage, EcsUriImage);
+    create_unit!(UriFile, EcsUriFile);
+    create_unit!(Color, EcsColor);
+    create_unit!(ColorRgb, EcsColorRgb);
+    create_unit!(ColorHsl, EcsColorHsl);
+    create_unit!(ColorCss, EcsColorCss);
+    create_unit!(Acceleration, EcsAcceleration);
+    create_unit!(Percentage, EcsPercentage);
+    create_unit!(Bel, EcsBel);
+    create_unit!(DeciBel, EcsDeciBel);
 }
 
 // Doc module components
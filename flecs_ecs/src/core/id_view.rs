@@ -139,6 +139,13 @@ impl<'a> std::ops::Deref for IdView<'a> {
     }
 }
 
+impl<'a> From<EntityView<'a>> for IdView<'a> {
+    #[inline]
+    fn from(entity: EntityView<'a>) -> Self {
+        IdView::new_from(entity.world, *entity.id)
+    }
+}
+
 impl<'a> IdView<'a> {
     /// checks if the id is a pair
     ///
@@ -355,6 +362,30 @@ impl<'a> IdView<'a> {
         let type_id = unsafe { sys::ecs_get_typeid(self.world.world_ptr(), *self.id) };
         EntityView::new_from(self.world, Entity(type_id))
     }
+
+    /// Get the size in bytes of the data stored by the id.
+    ///
+    /// The size is looked up through the type info of the id, so the same rules as for
+    /// [`IdView::type_id()`] apply to pairs. Tags, and pairs that don't store data, have a size of 0.
+    ///
+    /// # Returns
+    ///
+    /// The size of the component type in bytes, or 0 if the id has no data.
+    ///
+    /// # See also
+    ///
+    /// * [`IdView::type_id()`]
+    /// * C API: `ecs_get_type_info`
+    #[doc(alias = "ecs_get_type_info")]
+    #[inline(always)]
+    pub fn size(self) -> usize {
+        let type_info = unsafe { sys::ecs_get_type_info(self.world.world_ptr(), *self.id) };
+        if type_info.is_null() {
+            0
+        } else {
+            unsafe { (*type_info).size as usize }
+        }
+    }
 }
 
 impl<'a> IdOperations<'a> for IdView<'a> {
@@ -17,6 +17,7 @@ mod query;
 pub mod query_builder;
 mod query_iter;
 pub(crate) mod query_tuple;
+pub(crate) mod set_tuple;
 pub mod table;
 pub mod term;
 pub mod utility;
@@ -44,15 +45,16 @@ pub use observer_builder::ObserverBuilder;
 pub use query::Query;
 #[doc(hidden)]
 pub use query_builder::*;
-pub use query_iter::QueryIter;
+pub use query_iter::{QueryEntityIter, QueryIter, QueryMatchingIter};
 #[doc(hidden)]
 pub use query_tuple::*;
+pub use set_tuple::*;
 #[doc(hidden)]
 pub use table::*;
 #[doc(hidden)]
 pub use term::*;
 #[doc(hidden)]
 pub use utility::*;
-pub use world::World;
 pub(crate) use world::{FlecsArray, FlecsIdMap};
+pub use world::{World, WorldVersion};
 pub(crate) use world_ctx::*;
@@ -192,9 +192,9 @@ impl<'a, P, T: QueryTuple> ObserverBuilder<'a, P, T> {
 
     /// Invoke observer for anything that matches its query on creation
     ///
-    /// # Arguments
-    ///
-    /// * `should_yield` - If true, the observer will be invoked for all existing entities that match its query
+    /// The observer is invoked once for every existing entity that matches its query
+    /// when it is created. For `OnRemove` observers, the observer is also invoked for
+    /// all matching entities when it is deleted.
     ///
     /// # See also
     ///
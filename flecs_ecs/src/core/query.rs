@@ -1,6 +1,5 @@
 //! Queries are used to iterate over entities that match a query.
 
-use core::panic;
 use std::{marker::PhantomData, os::raw::c_void, ptr::NonNull};
 
 use flecs_ecs_sys::ecs_get_binding_ctx;
@@ -9,6 +8,8 @@ use sys::ecs_get_alive;
 use crate::core::*;
 use crate::sys;
 
+pub(crate) const INVALID_QUERY: &str = "Failed to create query, this is due to the user creating an invalid query. Most likely by using `expr` with a wrong expression.";
+
 /// Queries quickly find entities that match a list of conditions, and are at the core of many Flecs features like [systems], [observers], [tooling] and serialization.
 ///
 /// Flecs queries can do anything from returning entities that match a simple list of components, to matching complex patterns against entity graphs.
@@ -193,12 +194,20 @@ where
         world: impl WorldProvider<'a>,
         desc: &mut sys::ecs_query_desc_t,
     ) -> Self {
+        Self::try_new_from_desc(world, desc).expect(INVALID_QUERY)
+    }
+
+    /// Create a new query from a query descriptor, returning `None` if the query is invalid.
+    pub(crate) fn try_new_from_desc<'a>(
+        world: impl WorldProvider<'a>,
+        desc: &mut sys::ecs_query_desc_t,
+    ) -> Option<Self> {
         let world_ptr = world.world_ptr_mut();
 
         let query_ptr = unsafe { sys::ecs_query_init(world_ptr, desc) };
 
         if query_ptr.is_null() {
-            panic!("Failed to create query, this is due to the user creating an invalid query. Most likely by using `expr` with a wrong expression.");
+            return None;
         }
 
         unsafe {
@@ -208,11 +217,11 @@ where
 
             let query = NonNull::new_unchecked(query_ptr);
 
-            Self {
+            Some(Self {
                 query,
                 world_ctx,
                 _phantom: PhantomData,
-            }
+            })
         }
     }
 
@@ -231,11 +240,9 @@ where
                 );
 
                 if !query_poly.is_null() {
-                    sys::flecs_poly_claim_(query_poly as *mut c_void);
+                    let query_poly = (*(query_poly as *const sys::EcsPoly)).poly;
                     let query = NonNull::new_unchecked(query_poly as *mut sys::ecs_query_t);
-                    let new_query = Query::<()>::new_from(query);
-                    new_query.world().world_ctx_mut().inc_query_ref_count();
-                    return Some(new_query);
+                    return Some(Query::<()>::new_from(query));
                 }
             }
             None
@@ -244,32 +251,43 @@ where
 
     /// Destroy a query and its resources.
     ///
+    /// Queries associated with an entity (cached and named queries) are otherwise only
+    /// freed when the world is deleted, so dynamically created queries of that kind should
+    /// be destroyed with this method. Queries that are not associated with an entity are
+    /// freed when the last reference is dropped, for those this is the same as dropping.
+    ///
     /// If the query is used as the parent of subqueries, those subqueries will be
     /// orphaned and must be deinitialized as well.
     ///
+    /// # Panics
+    ///
+    /// Panics if the query is associated with an entity and other clones of it are still
+    /// alive, as those would refer to the freed query.
+    ///
     /// # See also
     ///
     /// * C++ API: `query_base::destruct`
     #[doc(alias = "query_base::destruct")]
     pub fn destruct(self) {
-        ecs_assert!(
-            unsafe { (*self.query.as_ptr()).entity } != 0,
-            "destruct() should only be called on queries associated with entities"
-        );
-
-        if unsafe { (*self.query.as_ptr()).entity } != 0 {
-            let world = self.world();
-            let world_ctx = world.world_ctx_mut();
-            world_ctx.dec_query_ref_count();
-            if unsafe { sys::flecs_poly_release_(self.query.as_ptr() as *mut c_void) } > 0 {
-                world_ctx.set_is_panicking_true();
-                unsafe { sys::ecs_query_fini(self.query.as_ptr()) };
-                panic!("The code base still has lingering references to `Query` objects. This is a bug in the user code. 
+        if unsafe { (*self.query.as_ptr()).entity } == 0 {
+            drop(self);
+            return;
+        }
+
+        let world = self.world();
+        let world_ctx = world.world_ctx_mut();
+        world_ctx.dec_query_ref_count();
+        if unsafe { sys::flecs_poly_release_(self.query.as_ptr() as *mut c_void) } > 0 {
+            world_ctx.set_is_panicking_true();
+            unsafe { sys::ecs_query_fini(self.query.as_ptr()) };
+            panic!("The code base still has lingering references to `Query` objects. This is a bug in the user code. 
                 Please ensure that all `Query` objects are out of scope that are a clone/copy of the current one.");
-            } else {
-                unsafe { sys::ecs_query_fini(self.query.as_ptr()) };
-            }
+        } else {
+            unsafe { sys::ecs_query_fini(self.query.as_ptr()) };
         }
+
+        // the query is freed, so `Drop` must not release it again
+        std::mem::forget(self);
     }
 
     pub(crate) fn reference_count(&self) -> i32 {
@@ -304,6 +322,16 @@ where
     ///
     /// Otherwise, it will return `false`.
     ///
+    /// # Performance
+    ///
+    /// Change detection is opt-in. It is only enabled for a query the first time this
+    /// function is called, which always returns `true`. From then on, the tables matched
+    /// by the query track a dirty counter per component, and every iteration of a query
+    /// that writes to those components updates it. Queries that never call this function
+    /// don't add any bookkeeping, so there is no need to disable change detection.
+    ///
+    /// Change detection is only supported for cached queries.
+    ///
     /// # See also
     ///
     /// * [`TableIter::is_changed()`]
@@ -3,6 +3,7 @@
 use std::ffi::c_void;
 
 use crate::core::internals::*;
+use crate::core::query::INVALID_QUERY;
 use crate::core::*;
 use crate::sys;
 
@@ -151,6 +152,28 @@ where
         T::populate(&mut obj);
         obj
     }
+
+    /// Build the query, returning `None` if the query is invalid.
+    ///
+    /// A query is invalid when, for example, the expression passed to `expr` fails
+    /// to parse or refers to entities that don't exist.
+    ///
+    /// # See also
+    ///
+    /// * [`Builder::build()`]
+    pub fn try_build(&mut self) -> Option<Query<T>> {
+        let query = Query::<T>::try_new_from_desc(self.world, &mut self.desc);
+        for string_parts in self.term_builder.str_ptrs_to_free.drain(..) {
+            unsafe {
+                String::from_raw_parts(
+                    string_parts.ptr as *mut u8,
+                    string_parts.len,
+                    string_parts.capacity,
+                );
+            }
+        }
+        query
+    }
 }
 
 #[doc(hidden)]
@@ -204,18 +227,7 @@ where
     /// * C++ API: `node_builder::build`
     #[doc(alias = "node_builder::build")]
     fn build(&mut self) -> Self::BuiltType {
-        let world = self.world;
-        let query = Query::<T>::new_from_desc(world, &mut self.desc);
-        for string_parts in self.term_builder.str_ptrs_to_free.iter() {
-            unsafe {
-                String::from_raw_parts(
-                    string_parts.ptr as *mut u8,
-                    string_parts.len,
-                    string_parts.capacity,
-                );
-            }
-        }
-        query
+        self.try_build().expect(INVALID_QUERY)
     }
 }
 
@@ -247,8 +259,16 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
 
     /// set itself to be instanced
     ///
+    /// Instanced queries return entities with fields that are not matched on the entity
+    /// itself (e.g. inherited from a prefab) in a single result for the whole table. Such
+    /// shared fields contain a single element that applies to all entities of the result,
+    /// while owned fields contain one element per entity.
+    ///
     /// # See also
     ///
+    /// * [`TableIter::is_instanced()`]
+    /// * [`TableIter::is_self()`]
+    ///
     /// * C++ API: `query_builder_i::instanced`
     #[doc(alias = "query_builder_i::instanced")]
     fn instanced(&mut self) -> &mut Self {
@@ -95,6 +95,51 @@ where
         self
     }
 
+    /// Limit results to a range of rows in a table
+    ///
+    /// This constrains the `$this` variable to the range, so only the rows
+    /// from `offset` to `offset + count` of the table are iterated. This is
+    /// useful for spreading the processing of a large table over time.
+    ///
+    /// # Arguments
+    ///
+    /// * `range`: the table range to iterate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut e = world.entity();
+    /// for i in 0..10 {
+    ///     e = world.entity().set(Position { x: i, y: 0 });
+    /// }
+    ///
+    /// let query = world.new_query::<&Position>();
+    /// let range = TableRange::new(e.table().unwrap(), 2, 3);
+    ///
+    /// let mut xs = vec![];
+    /// query.iterable().set_range(range).each(|p| xs.push(p.x));
+    /// assert_eq!(xs, [2, 3, 4]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryIter::set_var_table()`]
+    /// * C++ API: `iter_iterable::set_var`
+    #[doc(alias = "iter_iterable::set_var")]
+    pub fn set_range(&mut self, range: impl IntoTableRange) -> &mut Self {
+        self.set_var_table(0, range)
+    }
+
     /// set variable for rule iter
     ///
     /// # Arguments
@@ -194,4 +239,205 @@ where
     }
 }
 
-// TODO : worker_iterable and page_iterable not implemented yet
+/// A lending iterator over the entities matched by a query, yielding the entity together with its components.
+///
+/// Created by [`QueryAPI::iter_entities()`]. The components returned by [`QueryEntityIter::next()`] point into
+/// the table storage, which is locked until the iterator moves on to the next table or is dropped. Because the
+/// components borrow the iterator, they can't outlive the lock, so this type does not implement [`Iterator`].
+/// Structural changes must be deferred while iterating.
+pub struct QueryEntityIter<'a, T>
+where
+    T: QueryTuple,
+{
+    iter: sys::ecs_iter_t,
+    iter_next: unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool,
+    components_data: Option<T::Pointers>,
+    row: usize,
+    count: usize,
+    is_finished: bool,
+    _phantom: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> QueryEntityIter<'a, T>
+where
+    T: QueryTuple,
+{
+    pub(crate) fn new(
+        mut iter: sys::ecs_iter_t,
+        iter_next: unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool,
+    ) -> Self {
+        iter.flags |= sys::EcsIterIsInstanced;
+        iter.flags |= sys::EcsIterCppEach;
+
+        Self {
+            iter,
+            iter_next,
+            components_data: None,
+            row: 0,
+            count: 0,
+            is_finished: false,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    fn unlock_table(&mut self) {
+        if self.components_data.take().is_some() {
+            unsafe { sys::ecs_table_unlock(self.iter.world, self.iter.table) };
+        }
+    }
+
+    /// Moves on to the next table once the rows of the current table are exhausted.
+    ///
+    /// Returns `false` when there are no more entities.
+    fn load_row(&mut self) -> bool {
+        while self.row >= self.count {
+            if self.is_finished {
+                return false;
+            }
+
+            self.unlock_table();
+
+            if !unsafe { (self.iter_next)(&mut self.iter) } {
+                // the iterator is cleaned up by flecs once it's exhausted
+                self.is_finished = true;
+                return false;
+            }
+
+            ecs_assert!(
+                self.iter.count > 0,
+                FlecsErrorCode::InvalidOperation,
+                "no entities returned, use each() instead of iter_entities()",
+            );
+
+            self.components_data = Some(T::create_ptrs(&self.iter));
+            self.row = 0;
+            self.count = self.iter.count as usize;
+
+            unsafe { sys::ecs_table_lock(self.iter.world, self.iter.table) };
+        }
+        true
+    }
+
+    /// Advance to the next entity, returning `None` when all entities have been visited.
+    ///
+    /// The components borrow the iterator, so they must be dropped before the next call.
+    #[allow(clippy::should_implement_trait)] // lending: the components borrow the iterator
+    pub fn next(&mut self) -> Option<(EntityView<'a>, T::TupleType<'_>)> {
+        if !self.load_row() {
+            return None;
+        }
+
+        let row = self.row;
+        self.row += 1;
+
+        let world = unsafe { WorldRef::from_ptr(self.iter.world) };
+        let entity = EntityView::new_from(world, unsafe { *self.iter.entities.add(row) });
+        let tuple = self.components_data.as_mut()?.get_tuple(row);
+
+        Some((entity, tuple))
+    }
+
+    /// Advance to the next entity, returning its id.
+    ///
+    /// Unlike [`QueryEntityIter::next()`] the result does not borrow the table, so this can be
+    /// used with adaptors such as `filter` and `collect` through [`QueryEntityIter::entities()`].
+    pub fn next_entity(&mut self) -> Option<EntityView<'a>> {
+        self.next().map(|(entity, _)| entity)
+    }
+
+    /// Convert into a standard [`Iterator`] over the matched entities, without their components.
+    pub fn entities(mut self) -> impl Iterator<Item = EntityView<'a>> {
+        std::iter::from_fn(move || self.next_entity())
+    }
+}
+
+impl<T> Drop for QueryEntityIter<'_, T>
+where
+    T: QueryTuple,
+{
+    fn drop(&mut self) {
+        if !self.is_finished {
+            self.unlock_table();
+            unsafe { sys::ecs_iter_fini(&mut self.iter) };
+        }
+    }
+}
+
+/// A lending iterator over the entities of a query, filtered by whether they are matched by another query.
+///
+/// Created by [`QueryAPI::without_matching()`] and [`QueryAPI::with_matching()`]. The entities of the
+/// other query are collected when the iterator is created, so changes made while iterating do not
+/// affect which entities are filtered out. Like [`QueryEntityIter`], the components borrow the iterator.
+pub struct QueryMatchingIter<'a, T>
+where
+    T: QueryTuple,
+{
+    iter: QueryEntityIter<'a, T>,
+    others: std::collections::HashSet<u64, fxhash::FxBuildHasher>,
+    keep_matching: bool,
+}
+
+impl<'a, T> QueryMatchingIter<'a, T>
+where
+    T: QueryTuple,
+{
+    pub(crate) fn new(
+        iter: QueryEntityIter<'a, T>,
+        other: &impl IterOperations,
+        keep_matching: bool,
+    ) -> Self {
+        let mut others = std::collections::HashSet::default();
+        let mut other_iter = other.retrieve_iter();
+        // the iterator is cleaned up by flecs once it's exhausted
+        while other.iter_next(&mut other_iter) {
+            if other_iter.count > 0 {
+                let entities = unsafe {
+                    std::slice::from_raw_parts(other_iter.entities, other_iter.count as usize)
+                };
+                others.extend(entities.iter().copied());
+            }
+        }
+
+        Self {
+            iter,
+            others,
+            keep_matching,
+        }
+    }
+
+    fn skip_filtered(&mut self) -> bool {
+        // check the entity ids without creating the component references
+        while self.iter.load_row() {
+            let entity = unsafe { *self.iter.iter.entities.add(self.iter.row) };
+            if self.others.contains(&entity) == self.keep_matching {
+                return true;
+            }
+            self.iter.row += 1;
+        }
+        false
+    }
+
+    /// Advance to the next entity that passes the filter, returning `None` when all entities
+    /// have been visited.
+    ///
+    /// The components borrow the iterator, so they must be dropped before the next call.
+    #[allow(clippy::should_implement_trait)] // lending: the components borrow the iterator
+    pub fn next(&mut self) -> Option<(EntityView<'a>, T::TupleType<'_>)> {
+        if !self.skip_filtered() {
+            return None;
+        }
+        self.iter.next()
+    }
+
+    /// Advance to the next entity that passes the filter, returning its id.
+    pub fn next_entity(&mut self) -> Option<EntityView<'a>> {
+        self.next().map(|(entity, _)| entity)
+    }
+
+    /// Convert into a standard [`Iterator`] over the entities that pass the filter, without their components.
+    pub fn entities(mut self) -> impl Iterator<Item = EntityView<'a>> {
+        std::iter::from_fn(move || self.next_entity())
+    }
+}
+
+// TODO : worker_iterable not implemented yet
@@ -70,6 +70,18 @@ impl<T: QueryTuple, const LEN: usize> ComponentPointers<T> for ComponentsData<T,
 
 struct Singleton<T>(T);
 
+/// Tags and fields of empty tables have no column, in which case flecs returns a null
+/// pointer. References and slices may never be created from a null pointer, even when
+/// they are zero sized or empty, so use a dangling pointer with the proper alignment instead.
+#[inline(always)]
+fn ptr_or_dangling<T>(ptr: *mut u8) -> *mut T {
+    if ptr.is_null() {
+        std::ptr::NonNull::<T>::dangling().as_ptr()
+    } else {
+        ptr as *mut T
+    }
+}
+
 pub trait IterableTypeOperation {
     type CastType;
     type ActualType<'w>;
@@ -111,7 +123,8 @@ where
     }
 
     fn create_tuple_data<'a>(array_components_data: *mut u8, index: usize) -> Self::ActualType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe { &*data_ptr.add(index) }
     }
 
@@ -120,7 +133,8 @@ where
         is_ref: bool,
         index: usize,
     ) -> Self::ActualType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe {
             if is_ref {
                 &*data_ptr.add(0)
@@ -134,7 +148,8 @@ where
         array_components_data: *mut u8,
         count: usize,
     ) -> Self::SliceType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe { std::slice::from_raw_parts(data_ptr, count) }
     }
 
@@ -143,7 +158,8 @@ where
         is_ref_array_components: bool,
         count: usize,
     ) -> Self::SliceType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe {
             if is_ref_array_components {
                 std::slice::from_raw_parts(data_ptr, 1)
@@ -169,7 +185,8 @@ where
     }
 
     fn create_tuple_data<'a>(array_components_data: *mut u8, index: usize) -> Self::ActualType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe { &mut *data_ptr.add(index) }
     }
 
@@ -178,7 +195,8 @@ where
         is_ref: bool,
         index: usize,
     ) -> Self::ActualType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe {
             if is_ref {
                 &mut *data_ptr.add(0)
@@ -192,7 +210,8 @@ where
         array_components_data: *mut u8,
         count: usize,
     ) -> Self::SliceType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe { std::slice::from_raw_parts_mut(data_ptr, count) }
     }
 
@@ -201,7 +220,8 @@ where
         is_ref_array_components: bool,
         count: usize,
     ) -> Self::SliceType<'a> {
-        let data_ptr = array_components_data as Self::CastType;
+        let data_ptr = ptr_or_dangling::<<T as ComponentOrPairId>::CastType>(array_components_data)
+            as Self::CastType;
         unsafe {
             if is_ref_array_components {
                 std::slice::from_raw_parts_mut(data_ptr, 1)
@@ -0,0 +1,29 @@
+use crate::core::*;
+use flecs_ecs_derive::tuples;
+
+/// A tuple of owned components that can be set on an entity at once.
+///
+/// # See also
+///
+/// * [`EntityView::set_many()`]
+pub trait SetTuple: Sized {
+    /// Set each component of the tuple on the entity.
+    fn set_components(self, entity: EntityView);
+}
+
+macro_rules! impl_set_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: ComponentId + DataComponent),*> SetTuple for ($($t,)*) {
+            #[allow(unused, non_snake_case)]
+            fn set_components(self, entity: EntityView) {
+                let world = entity.world;
+                let ($($t,)*) = self;
+                $(
+                    set_helper(world.world_ptr_mut(), *entity.id, $t, $t::id(world));
+                )*
+            }
+        }
+    }
+}
+
+tuples!(impl_set_tuple, 0, 16);
@@ -135,6 +135,9 @@ where
 
     /// Return the count of entities in the iterator.
     ///
+    /// This is the number of rows in the current table. It can be zero, for example for
+    /// queries that match empty tables, in which case the fields are empty slices.
+    ///
     /// # See also
     ///
     /// * C++ API: `iter::count`
@@ -146,6 +149,18 @@ where
         self.iter.count as usize
     }
 
+    /// Return whether the current table has no entities.
+    ///
+    /// Queries that match empty tables invoke callbacks for tables without rows. This can
+    /// be used to skip such tables before accessing any fields.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::count()`]
+    pub fn is_empty(&self) -> bool {
+        self.iter.count == 0
+    }
+
     /// Return the delta time stored in the iterator.
     ///
     /// This is the time since the last frame.
@@ -172,6 +187,18 @@ where
         self.iter.delta_system_time
     }
 
+    /// Set the entity that is returned by [`World::run_system()`].
+    ///
+    /// Manual systems can use this in combination with a `param` to look up
+    /// an entity and return it to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to return from the system run.
+    pub fn set_interrupted_by(&mut self, entity: impl Into<Entity>) {
+        self.iter.interrupted_by = *entity.into();
+    }
+
     /// Return the table stored in the iterator as an `Archetype` object
     ///
     /// # See also
@@ -182,8 +209,44 @@ where
         self.table().map(|t| t.archetype())
     }
 
+    /// Return the table the iterator is currently pointing at
+    ///
+    /// The returned [`Table`] is the archetype of the entities in the current
+    /// iteration, which makes it possible to do bulk operations on the table
+    /// from within query, system and observer callbacks.
+    ///
+    /// # Returns
+    ///
+    /// The current table, or `None` if the iterator does not point at a table
+    /// (for example when iterating a query without `$this` variable).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// world.new_query::<&Position>().run(|mut it| {
+    ///     while it.next() {
+    ///         let table = it.table().unwrap();
+    ///         assert!(table.find_type_index::<Position>().is_some());
+    ///         assert_eq!(table.count(), it.count() as i32);
+    ///     }
+    /// });
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`TableIter::range()`]
     /// * C++ API: `iter::table`
     #[doc(alias = "iter::table")]
     pub fn table(&self) -> Option<Table<'a>> {
@@ -219,12 +282,18 @@ where
 
     /// Get the variable of the iterator by name
     ///
+    /// Returns the entity bound to a query variable, such as `$parent` in
+    /// `ChildOf($this, $parent)`, for the current result.
+    ///
     /// # Arguments
     ///
-    /// * `var_id` - The variable id
+    /// * `name` - The name of the variable, without the `$` prefix. The query must
+    ///   have a variable with this name.
     ///
     /// # See also
     ///
+    /// * [`TableIter::get_var()`]
+    /// * [`QueryAPI::find_var()`]
     /// * C++ API: `iter::get_var`
     #[doc(alias = "iter::get_var")]
     pub fn get_var_by_name(&self, name: &str) -> EntityView<'a> {
@@ -283,6 +352,42 @@ where
         self.iter.param
     }
 
+    /// Access the parameter passed to a manually run system.
+    ///
+    /// Returns `None` if the system was run without a parameter.
+    ///
+    /// # Safety
+    ///
+    /// - Caller must ensure the parameter passed to the run function is of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// struct Threshold(i32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let system = world.system::<()>().run(|mut it| {
+    ///     while it.next() {}
+    ///     let threshold = unsafe { it.param_as::<Threshold>() };
+    ///     assert_eq!(threshold.map(|t| t.0), Some(10));
+    /// });
+    ///
+    /// world.run_system_with_param(system, 0.0, &Threshold(10));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::run_system_with_param()`]
+    /// * [`TableIter::param_untyped()`]
+    /// * C++ API: `iter::param`
+    #[doc(alias = "iter::param")]
+    pub unsafe fn param_as<T>(&self) -> Option<&'a T> {
+        unsafe { (self.iter.param as *const T).as_ref() }
+    }
+
     /// Access param.
     /// param contains the pointer passed to the param argument of `system::run` or the event payload
     ///
@@ -311,6 +416,13 @@ where
         unsafe { &*ptr }
     }
 
+    /// Test if a field is owned by the entities of the current table.
+    ///
+    /// A field that is not matched on self is shared: its data comes from another entity,
+    /// such as a prefab or a parent, and the field holds a single value for all entities.
+    /// This is the same information the typed iteration functions use to decide whether to
+    /// index a field by row.
+    ///
     /// # Arguments
     ///
     /// * `index` - Index of the field to check
@@ -321,6 +433,7 @@ where
     ///
     /// # See also
     ///
+    /// * [`TableIter::src()`]
     /// * C++ API: `iter::is_self`
     #[doc(alias = "iter::is_self")]
     pub fn is_self(&self, index: i32) -> bool {
@@ -359,6 +472,31 @@ where
         unsafe { sys::ecs_field_is_readonly(self.iter, index) }
     }
 
+    /// Test whether the iterator is instanced.
+    ///
+    /// Instanced iterators return fields that are not matched on the entity itself
+    /// (e.g. inherited from a prefab) together with the owned fields of the table,
+    /// instead of returning each entity separately.
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::instanced()`]
+    /// * [`TableIter::is_self()`]
+    pub fn is_instanced(&self) -> bool {
+        self.iter.flags & sys::EcsIterIsInstanced != 0
+    }
+
+    /// Test whether the iterator points to a valid result.
+    ///
+    /// This is true after a call to [`TableIter::next()`] returned `true`.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::next()`]
+    pub fn is_valid(&self) -> bool {
+        self.iter.flags & sys::EcsIterIsValid != 0
+    }
+
     /// Number of fields in iterator.
     ///
     /// # See also
@@ -513,7 +651,7 @@ where
     fn field_checked<T: ComponentId>(&self, index: i32) -> Option<Field<T::UnderlyingType>> {
         let id = <T::UnderlyingType as ComponentId>::id(self.world());
 
-        if index > self.iter.field_count {
+        if index < 0 || index >= self.iter.field_count {
             return None;
         }
 
@@ -763,6 +901,22 @@ where
         self.iter.group_id
     }
 
+    /// Return the group of the current table as an entity.
+    ///
+    /// With the default `group_by` callback the group id is the target of the
+    /// grouping relationship, which is always an entity. Only use this with
+    /// custom `group_by` callbacks that also return entity ids.
+    ///
+    /// This is only meaningful for grouped queries. For other queries the group id
+    /// is 0, which is not a valid entity.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::group_id()`]
+    pub fn group_entity(&self) -> EntityView<'a> {
+        EntityView::new_from(self.real_world(), self.iter.group_id)
+    }
+
     unsafe fn field_internal<T>(&self, index: i32) -> Option<Field<T>> {
         let is_shared = !self.is_self(index);
 
@@ -854,11 +1008,18 @@ where
             }
         };
 
-        self.iter.flags |= sys::EcsIterIsValid;
-        if result && !self.iter.table.is_null() {
-            unsafe {
-                sys::ecs_table_lock(self.iter.world, self.iter.table);
-            };
+        if result {
+            self.iter.flags |= sys::EcsIterIsValid;
+            if !self.iter.table.is_null() {
+                unsafe {
+                    sys::ecs_table_lock(self.iter.world, self.iter.table);
+                };
+            }
+        } else {
+            // flecs finalized the iterator, clearing `next` marks it as done so
+            // it is not finalized a second time
+            self.iter.flags &= !sys::EcsIterIsValid;
+            self.iter.next = None;
         }
 
         result
@@ -868,8 +1029,9 @@ where
     /// This operation only needs to be called when the iterator is not iterated
     /// until completion (e.g. the last call to `next()` did not return false).
     ///
-    /// Failing to call this operation on an unfinished iterator will throw a
-    /// `fatal LEAK_DETECTED` error.
+    /// Iterators passed to `run` callbacks are finalized automatically when the
+    /// callback returns early, so calling this is optional there. Calling it on
+    /// an iterator that is already finished does nothing.
     ///
     /// # Example
     ///
@@ -892,12 +1054,30 @@ where
     ///     });
     /// ```
     pub fn fini(self) {
-        unsafe {
-            sys::ecs_iter_fini(self.iter);
-        }
+        unsafe { fini_unfinished(self.iter) };
     }
 }
 
+/// Unlocks the current table and finalizes `iter`, unless it was already
+/// iterated until completion or finalized.
+///
+/// # Safety
+///
+/// `iter` must have been created by a query iterator and only advanced through
+/// [`TableIter::next`].
+pub(crate) unsafe fn fini_unfinished(iter: &mut sys::ecs_iter_t) {
+    if iter.next.is_none() {
+        return;
+    }
+
+    if iter.flags & sys::EcsIterIsValid != 0 && !iter.table.is_null() {
+        sys::ecs_table_unlock(iter.world, iter.table);
+    }
+
+    sys::ecs_iter_fini(iter);
+    iter.next = None;
+}
+
 /// Iterator to iterate over rows in a table
 pub struct TableRowIter<'a, const IS_RUN: bool, P> {
     iter: &'a TableIter<'a, IS_RUN, P>,
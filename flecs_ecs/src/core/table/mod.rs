@@ -4,6 +4,7 @@ mod field;
 mod iter;
 
 pub use field::{Field, FieldUntyped};
+pub(crate) use iter::fini_unfinished;
 pub use iter::{TableIter, TableRowIter};
 
 use std::{ffi::CStr, os::raw::c_void, ptr::NonNull};
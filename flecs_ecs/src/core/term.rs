@@ -643,6 +643,48 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
         }
     }
 
+    /// Select second identifier, initialize it with the wildcard.
+    ///
+    /// This turns the current term into a `(First, *)` pair, which matches the
+    /// relationship with any target. The matched target can be read back
+    /// during iteration with [`TableIter::pair()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity_named("parent");
+    /// world.entity().add::<Enemy>().child_of_id(parent);
+    ///
+    /// world
+    ///     .query::<()>()
+    ///     .with::<Enemy>()
+    ///     .with::<flecs::ChildOf>()
+    ///     .second_wildcard()
+    ///     .build()
+    ///     .run(|mut it| {
+    ///         while it.next() {
+    ///             let pair = it.pair(1).unwrap();
+    ///             assert_eq!(pair.second_id(), parent);
+    ///         }
+    ///     });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`TermBuilderImpl::set_second_id()`]
+    /// * [`TableIter::pair()`]
+    fn second_wildcard(&mut self) -> &mut Self {
+        check_term_access_validity(self);
+        self.set_second_id(flecs::Wildcard::ID)
+    }
+
     /// default up where trav is set to 0.
     /// The up flag indicates that the term identifier may be substituted by
     /// traversing a relationship upwards. For example: substitute the identifier
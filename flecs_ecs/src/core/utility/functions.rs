@@ -33,8 +33,21 @@ pub fn ecs_entity_id_combine(lo: u64, hi: u64) -> u64 {
 /// # Returns
 ///
 /// The combined 64 bit integer with the `ECS_PAIR` flag set.
+///
+/// # Panics
+///
+/// In debug builds, panics if `rel` is 0. This usually means the pair was built from
+/// a component that wasn't registered with the world yet. `target` may be 0, since
+/// `(ChildOf, 0)` is the id flecs uses for entities in the root scope.
 #[inline(always)]
 pub fn ecs_pair(rel: u64, target: u64) -> u64 {
+    ecs_assert!(
+        rel != 0,
+        FlecsErrorCode::InvalidParameter,
+        "invalid pair ({}, {}): pair relationship must not be 0",
+        rel,
+        target
+    );
     ECS_PAIR | ecs_entity_id_combine(target, rel)
 }
 
@@ -286,6 +299,7 @@ pub(crate) fn set_helper<T: ComponentId>(
         } else
         /* not deferred */
         {
+            check_table_not_locked(world, entity, id, true);
             let ptr = sys::ecs_emplace_id(world, entity, id, &mut is_new) as *mut T;
 
             if !is_new {
@@ -297,6 +311,40 @@ pub(crate) fn set_helper<T: ComponentId>(
     }
 }
 
+/// Asserts that adding or removing `id` does not move the entity out of a
+/// table that is locked by an iterator.
+///
+/// Structural changes to an entity in a table that is being iterated are only
+/// allowed when the world is deferred, since they would move the entity and
+/// invalidate the component slices handed out by the iterator.
+///
+/// Adding an id the entity only inherits overrides it, which also moves the
+/// entity, so the check looks at the ids the entity owns.
+///
+/// The check uses `ecs_assert`, so it only runs in debug builds or with the
+/// `flecs_force_enable_ecs_asserts` feature.
+#[inline(always)]
+#[allow(unused_variables)]
+pub(crate) fn check_table_not_locked(
+    world: *mut sys::ecs_world_t,
+    entity: u64,
+    id: u64,
+    add: bool,
+) {
+    ecs_assert!(
+        unsafe {
+            sys::ecs_is_deferred(world)
+                || sys::ecs_owns_id(world, entity, id) == add
+                || {
+                    let table = sys::ecs_get_table(world, entity);
+                    table.is_null() || !sys::ecs_rust_table_is_locked(table)
+                }
+        },
+        FlecsErrorCode::LockedStorage,
+        "cannot add or remove components of an entity while its table is being iterated, use defer_begin/defer_end"
+    );
+}
+
 /// Remove generation from entity id.
 ///
 /// # Arguments
@@ -369,6 +417,61 @@ pub unsafe fn ecs_field<T: ComponentId>(it: *const sys::ecs_iter_t, index: i32)
     sys::ecs_field_w_size(it, size, index) as *mut T
 }
 
+/// Get a pointer to the element of a field for a specific row.
+///
+/// Unlike [`ecs_field`], which returns the base of the field column, this returns a pointer to the
+/// element for `row`. Fields that are not owned by the entity being iterated (see `ecs_field_is_self`)
+/// have a single element, which is returned for any row.
+///
+/// Sparse components are not stored in table columns. Queries with sparse `$this` fields return
+/// their results one entity at a time, with the field pointing at that entity's element, so `row`
+/// must be `0` for those.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences the iterator and uses the index to get the component data.
+/// Ensure that the iterator is valid and the index is valid.
+///
+/// # Arguments
+///
+/// - `it`: A pointer to the iterator.
+/// - `index`: The index of the field in the iterator, starting from 0.
+/// - `row`: The row in the current result, starting from 0.
+///
+/// # Returns
+///
+/// A pointer to the element of the specified field, or null if the field is not set.
+///
+/// # Example
+///
+/// ```ignore
+/// // Assuming `it` is a valid iterator pointer obtained from a query.
+/// for row in 0..(*it).count {
+///     let position: *mut Position = ecs_field_at(it, 0, row);
+/// }
+/// ```
+#[inline(always)]
+pub unsafe fn ecs_field_at<T: ComponentId>(
+    it: *const sys::ecs_iter_t,
+    index: i32,
+    row: i32,
+) -> *mut T {
+    let ptr = ecs_field::<T>(it, index);
+    if ptr.is_null() || !sys::ecs_field_is_self(it, index) {
+        return ptr;
+    }
+
+    ecs_assert!(
+        row >= 0 && row < (*it).count,
+        FlecsErrorCode::OutOfRange,
+        "row {} is out of range for a result of {} entities",
+        row,
+        (*it).count
+    );
+
+    ptr.add(row as usize)
+}
+
 /// Get the `OperKind` for the given type.
 ///
 /// # Type Parameters
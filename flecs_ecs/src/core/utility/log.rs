@@ -1,6 +1,20 @@
 //! sets various internal logging options
+use std::ffi::{c_char, CStr};
+use std::sync::{Arc, Once, OnceLock, RwLock};
+
 use crate::sys;
 
+type LogHook = Arc<dyn Fn(i32, &str) + Send + Sync>;
+
+/// The Rust callback that receives flecs log messages.
+static LOG_HOOK: RwLock<Option<LogHook>> = RwLock::new(None);
+
+/// The flecs log callback that was installed before the hook, used when the hook is removed.
+static DEFAULT_LOG: OnceLock<sys::ecs_os_api_log_t> = OnceLock::new();
+
+/// Guards installing [`log_hook`] into the OS API, which only happens once.
+static INSTALL_LOG_HOOK: Once = Once::new();
+
 /// Sets the logging level to the specified value.
 ///
 /// # Arguments
@@ -53,3 +67,77 @@ pub fn enable_timedelta_logging(enabled: bool) {
         sys::ecs_log_enable_timedelta(enabled);
     }
 }
+
+/// Routes flecs log messages to a Rust callback, e.g. to forward them to the `log` crate.
+///
+/// The callback receives the flecs log level and the formatted message. Levels follow
+/// flecs: `-4` is fatal, `-3` an error, `-2` a warning, `0` info and positive values are
+/// debug / trace levels. Messages above the level set with [`set_log_level`] are not
+/// passed to the callback.
+///
+/// The hook is process wide and replaces any previously installed hook. The callback may
+/// install or remove hooks itself, but must not panic, as it is invoked from C.
+///
+/// The first call routes the flecs OS API log callback through this module, which is not
+/// synchronized with threads that are logging. Make the first call before running any
+/// world with worker threads. Later calls and [`remove_log_hook`] only swap the callback
+/// and can be made at any time.
+///
+/// # Arguments
+///
+/// * `hook` - The callback invoked for each log message.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// set_log_hook(|level, msg| match level {
+///     ..=-3 => eprintln!("flecs error: {msg}"),
+///     -2 => eprintln!("flecs warning: {msg}"),
+///     _ => println!("flecs: {msg}"),
+/// });
+///
+/// remove_log_hook();
+/// ```
+///
+/// # See also
+///
+/// * [`remove_log_hook`]
+pub fn set_log_hook(hook: impl Fn(i32, &str) + Send + Sync + 'static) {
+    *LOG_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+
+    INSTALL_LOG_HOOK.call_once(|| unsafe {
+        // make sure the defaults are in place, as initializing a world resets the
+        // OS API to the defaults until it is set explicitly
+        sys::ecs_os_set_api_defaults();
+        DEFAULT_LOG.get_or_init(|| sys::ecs_os_api.log_);
+
+        let mut api = sys::ecs_os_get_api();
+        api.log_ = Some(log_hook);
+        sys::ecs_os_set_api(&mut api);
+        // `ecs_os_set_api` does nothing when the OS API was already set
+        sys::ecs_os_api.log_ = Some(log_hook);
+    });
+}
+
+/// Removes the callback installed with [`set_log_hook`], so flecs logs to its own sink again.
+///
+/// # See also
+///
+/// * [`set_log_hook`]
+pub fn remove_log_hook() {
+    *LOG_HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+unsafe extern "C" fn log_hook(level: i32, file: *const c_char, line: i32, msg: *const c_char) {
+    // the lock is released before calling the hook, so it can replace or remove itself
+    let hook = LOG_HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        if !msg.is_null() {
+            hook(level, &CStr::from_ptr(msg).to_string_lossy());
+        }
+    } else if let Some(Some(default_log)) = DEFAULT_LOG.get() {
+        default_log(level, file, line, msg);
+    }
+}
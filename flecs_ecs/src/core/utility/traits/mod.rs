@@ -16,7 +16,7 @@ pub use query_api::*;
 pub use system_api::*;
 pub use world_provider::*;
 
-use crate::core::{ImplementsClone, ImplementsDefault};
+use crate::core::{ImplementsClone, ImplementsDefault, ImplementsMeta, MetaComponent};
 
 #[doc(hidden)]
 pub mod private {
@@ -47,6 +47,8 @@ pub mod private {
             run_ctx_free: flecs_ecs_sys::ecs_ctx_free_t,
         ) -> &mut Self;
 
+        fn set_context_free(&mut self, ctx_free: sys::ecs_ctx_free_t) -> &mut Self;
+
         fn desc_binding_context(&self) -> *mut c_void;
 
         fn set_desc_callback(
@@ -302,6 +304,10 @@ impl<T: Default> ImplementsDefault<T> {
     pub const IMPLS: bool = true;
 }
 
+impl<T: MetaComponent> ImplementsMeta<T> {
+    pub const IMPLS: bool = true;
+}
+
 pub trait FlecsConstantId {
     const ID: u64;
 }
@@ -1,5 +1,7 @@
 use std::ffi::c_char;
+use std::ptr::NonNull;
 
+use flecs_ecs::core::table::fini_unfinished;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
@@ -35,35 +37,42 @@ where
     ///
     /// Each iterators are automatically instanced.
     ///
+    /// # Panics
+    ///
+    /// In debug builds, adding or removing components on an entity in the table that
+    /// is being iterated panics with `LockedStorage`, unless the world is deferred.
+    ///
     /// # See also
     ///
     /// * [`World::each()`]
+    /// * [`QueryAPI::each_iter()`] to also access the iterator, e.g. for the entity or the delta time
     /// * C++ API: `iterable::each`
     #[doc(alias = "iterable::each")]
     fn each(&self, mut func: impl FnMut(T::TupleType<'_>)) {
-        unsafe {
-            let mut iter = self.retrieve_iter();
-            iter.flags |= sys::EcsIterIsInstanced;
-            iter.flags |= sys::EcsIterCppEach;
-
-            while self.iter_next(&mut iter) {
-                let mut components_data = T::create_ptrs(&iter);
-                let iter_count = {
-                    if iter.count == 0 && iter.table.is_null() {
-                        1_usize
-                    } else {
-                        iter.count as usize
-                    }
-                };
-
-                sys::ecs_table_lock(self.world_ptr_mut(), iter.table);
-
-                for i in 0..iter_count {
-                    let tuple = components_data.get_tuple(i);
-                    func(tuple);
+        let mut iter = self.retrieve_iter();
+        // the iterator is only accessed through this pointer, which is shared with the guard
+        let iter = std::ptr::addr_of_mut!(iter);
+        let _fini = IterFiniOnUnwind(iter);
+        let iter = unsafe { &mut *iter };
+        iter.flags |= sys::EcsIterIsInstanced;
+        iter.flags |= sys::EcsIterCppEach;
+
+        while self.iter_next(iter) {
+            let mut components_data = T::create_ptrs(iter);
+            let iter_count = {
+                if iter.count == 0 && iter.table.is_null() {
+                    1_usize
+                } else {
+                    iter.count as usize
                 }
+            };
 
-                sys::ecs_table_unlock(self.world_ptr_mut(), iter.table);
+            // unlocks the table when dropped, also when `func` panics
+            let _lock = NonNull::new(iter.table).map(|t| TableLock::new(self.world(), t));
+
+            for i in 0..iter_count {
+                let tuple = components_data.get_tuple(i);
+                func(tuple);
             }
         }
     }
@@ -75,6 +84,11 @@ where
     ///
     /// Each iterators are automatically instanced.
     ///
+    /// # Panics
+    ///
+    /// In debug builds, adding or removing components on an entity in the table that
+    /// is being iterated panics with `LockedStorage`, unless the world is deferred.
+    ///
     /// # See also
     ///
     /// * [`World::each_entity()`]
@@ -82,13 +96,16 @@ where
     #[doc(alias = "iterable::each")]
     fn each_entity(&self, mut func: impl FnMut(EntityView, T::TupleType<'_>)) {
         unsafe {
-            let world = self.world_ptr_mut();
             let mut iter = self.retrieve_iter();
+            // the iterator is only accessed through this pointer, which is shared with the guard
+            let iter = std::ptr::addr_of_mut!(iter);
+            let _fini = IterFiniOnUnwind(iter);
+            let iter = &mut *iter;
             iter.flags |= sys::EcsIterIsInstanced;
             iter.flags |= sys::EcsIterCppEach;
 
-            while self.iter_next(&mut iter) {
-                let mut components_data = T::create_ptrs(&iter);
+            while self.iter_next(iter) {
+                let mut components_data = T::create_ptrs(iter);
                 let iter_count = {
                     if iter.count == 0 && iter.table.is_null() {
                         1_usize
@@ -103,7 +120,8 @@ where
                     "no entities returned, use each() without flecs::entity argument",
                 );
 
-                sys::ecs_table_lock(world, iter.table);
+                // unlocks the table when dropped, also when `func` panics
+                let _lock = NonNull::new(iter.table).map(|t| TableLock::new(self.world(), t));
 
                 // TODO random thought, I think I can determine the elements is a ref or not before the for loop and then pass two arrays with the indices of the ref and non ref elements
                 // I will come back to this in the future, my thoughts are somewhere else right now. If my assumption is correct, this will get rid of the branch in the for loop
@@ -116,8 +134,6 @@ where
 
                     func(EntityView::new_from(world, *iter.entities.add(i)), tuple);
                 }
-
-                sys::ecs_table_unlock(world, iter.table);
             }
         }
     }
@@ -127,6 +143,9 @@ where
     /// The `usize` argument contains the index of the entity being iterated,
     /// which can be used to obtain entity-specific data from the `TableIter` object.
     ///
+    /// This gives a single callback access to the components of a row and to the iteration
+    /// state, such as [`TableIter::entity()`] for the row and [`TableIter::delta_time()`] in systems.
+    ///
     /// # Example
     /// ```
     /// use flecs_ecs::prelude::*;
@@ -233,6 +252,11 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity.is_some() {
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity
         }
@@ -281,6 +305,11 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity_result.is_some() {
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity_result
         }
@@ -337,6 +366,11 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity_result.is_some() {
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity_result
         }
@@ -438,6 +472,82 @@ where
         }
     }
 
+    /// Page iterator. Like [`run_iter()`](Self::run_iter), but only iterates a window of the results.
+    ///
+    /// The window skips the first `offset` entities of the whole result set and yields at most
+    /// `limit` entities, across table boundaries. Results of the window are passed to the
+    /// function per table, so a window spanning multiple tables invokes the function once for each.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: the number of entities to skip
+    /// * `limit`: the maximum number of entities to iterate, or 0 for no limit
+    /// * `func`: the function invoked for each table of the window
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// for i in 0..10 {
+    ///     world.entity().set(Position { x: i, y: 0 });
+    /// }
+    ///
+    /// let mut xs = vec![];
+    /// world.new_query::<&Position>().page(4, 3, |it, pos| {
+    ///     for i in it.iter() {
+    ///         xs.push(pos[i].x);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(xs, [4, 5, 6]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryIter::set_range()`]
+    /// * C++ API: `iter_iterable::page`
+    /// * C API: `ecs_page_iter`
+    #[doc(alias = "iter_iterable::page")]
+    #[doc(alias = "ecs_page_iter")]
+    fn page(
+        &self,
+        offset: i32,
+        limit: i32,
+        mut func: impl FnMut(TableIter<false, P>, T::TupleSliceType<'_>),
+    ) where
+        P: ComponentId,
+    {
+        unsafe {
+            let mut iter = self.retrieve_iter();
+            // the page iterator pulls results from `iter`, and cleans it up once it
+            // is depleted or the limit is reached
+            let mut page = sys::ecs_page_iter(std::ptr::addr_of_mut!(iter), offset, limit);
+            let world = self.world_ptr_mut();
+
+            while sys::ecs_page_next(&mut page) {
+                let mut components_data = T::create_ptrs(&page);
+                let iter_count = page.count as usize;
+
+                sys::ecs_table_lock(world, page.table);
+
+                let tuple = components_data.get_slice(iter_count);
+                let iter_t = TableIter::new(&mut page);
+                func(iter_t, tuple);
+
+                sys::ecs_table_unlock(world, page.table);
+            }
+        }
+    }
+
     /// Run iterator.
     ///
     /// The "run" iterator accepts a function that is invoked for each matching
@@ -522,25 +632,13 @@ where
         P: ComponentId,
     {
         let mut iter = self.retrieve_iter();
-        let mut iter_t = unsafe { TableIter::new(&mut iter) };
+        // the iterator is only accessed through this pointer, which is shared with the guard
+        let iter = std::ptr::addr_of_mut!(iter);
+        // finalizes the iterator when `func` returns before it is exhausted
+        let _fini = IterFini(iter);
+        let mut iter_t = unsafe { TableIter::new(&mut *iter) };
         iter_t.iter_mut().flags &= !sys::EcsIterIsValid;
         func(iter_t);
-
-        //TODO flecs will integrate this before V4 release.
-        // // if the while loop with `iter_next()` exited early, the table should be unlocked
-        // // and iter should be cleaned up
-        // if (iter.flags & sys::EcsIterIsValid) != 0 && !iter.table.is_null() {
-        //     unsafe {
-        //         sys::ecs_table_unlock(iter.world, iter.table);
-        //         sys::ecs_iter_fini(&mut iter)
-        //     };
-        // }
-
-        // ecs_assert!(
-        //     iter.flags & sys::EcsIterIsValid != 0,
-        //     FlecsErrorCode::InvalidOperation,
-        //     "when using `run()`, you must call `while it.next()` in the callback"
-        // );
     }
 
     /// Run iterator with each forwarding.
@@ -633,11 +731,16 @@ where
             __internal_query_execute_each::<T, FuncEach>
                 as unsafe extern "C" fn(*mut sys::ecs_iter_t),
         );
-        let mut iter_t = unsafe { TableIter::new(&mut iter) };
+        // the iterator is only accessed through this pointer, which is shared with the guard
+        let iter = std::ptr::addr_of_mut!(iter);
+        let _fini = IterFini(iter);
+        let mut iter_t = unsafe { TableIter::new(&mut *iter) };
         iter_t.iter_mut().flags &= !sys::EcsIterIsValid;
         func(iter_t);
-        iter.callback = None;
-        iter.callback_ctx = std::ptr::null_mut();
+        unsafe {
+            (*iter).callback = None;
+            (*iter).callback_ctx = std::ptr::null_mut();
+        }
     }
 
     /// Run iterator with each entity forwarding.
@@ -733,11 +836,16 @@ where
             __internal_query_execute_each_entity::<T, FuncEachEntity>
                 as unsafe extern "C" fn(*mut sys::ecs_iter_t),
         );
-        let mut iter_t = unsafe { TableIter::new(&mut iter) };
+        // the iterator is only accessed through this pointer, which is shared with the guard
+        let iter = std::ptr::addr_of_mut!(iter);
+        let _fini = IterFini(iter);
+        let mut iter_t = unsafe { TableIter::new(&mut *iter) };
         iter_t.iter_mut().flags &= !sys::EcsIterIsValid;
         func(iter_t);
-        iter.callback = None;
-        iter.callback_ctx = std::ptr::null_mut();
+        unsafe {
+            (*iter).callback = None;
+            (*iter).callback_ctx = std::ptr::null_mut();
+        }
     }
 
     /// Get the entity of the current query
@@ -893,6 +1001,117 @@ where
         QueryIter::new(self.retrieve_iter_stage(stage), self.iter_next_func())
     }
 
+    /// Returns a lending iterator over all matched entities and their components.
+    ///
+    /// Unlike [`QueryAPI::each_entity()`] the caller drives the iteration, so it can stop early or
+    /// interleave it with other code. The components borrow the iterator, as they point into table
+    /// storage that is only locked while the iterator is on that table. Use
+    /// [`QueryEntityIter::entities()`] for a standard [`Iterator`] over just the entities.
+    /// Structural changes (adding or removing components, deleting entities) must be deferred
+    /// until the iterator is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("a").set(Position { x: 1, y: 2 });
+    /// world.entity_named("b").set(Position { x: 3, y: 4 });
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// let mut names = vec![];
+    /// let mut iter = query.iter_entities();
+    /// while let Some((e, p)) = iter.next() {
+    ///     if p.x > 1 {
+    ///         names.push(e.name());
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(names, ["b"]);
+    /// ```
+    fn iter_entities(&self) -> QueryEntityIter<'a, T> {
+        QueryEntityIter::new(self.retrieve_iter(), self.iter_next_func())
+    }
+
+    /// Iterate the entities of this query that are not matched by another query.
+    ///
+    /// This is the difference of the two queries: entities matched by `other` are skipped. The
+    /// entities of `other` are collected up front, after which this query is iterated lazily.
+    /// Since the components of this query are returned, the queries don't need to have the same
+    /// terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The query with the entities to exclude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("a").set(Position { x: 1, y: 2 });
+    /// world
+    ///     .entity_named("b")
+    ///     .set(Position { x: 3, y: 4 })
+    ///     .add::<Frozen>();
+    ///
+    /// let moving = world.new_query::<&Position>();
+    /// let frozen = world.new_query::<&Frozen>();
+    ///
+    /// let names: Vec<&str> = moving
+    ///     .without_matching(&frozen)
+    ///     .entities()
+    ///     .map(|e| e.name())
+    ///     .collect();
+    ///
+    /// assert_eq!(names, ["a"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::with_matching()`]
+    /// * [`QueryAPI::iter_entities()`]
+    fn without_matching(&self, other: &impl IterOperations) -> QueryMatchingIter<'a, T> {
+        QueryMatchingIter::new(self.iter_entities(), other, false)
+    }
+
+    /// Iterate the entities of this query that are also matched by another query.
+    ///
+    /// This is the intersection of the two queries, with the components of this query. The
+    /// entities of `other` are collected up front, after which this query is iterated lazily.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The query an entity must also match.
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::without_matching()`]
+    /// * [`QueryAPI::iter_entities()`]
+    fn with_matching(&self, other: &impl IterOperations) -> QueryMatchingIter<'a, T> {
+        QueryMatchingIter::new(self.iter_entities(), other, true)
+    }
+
     /// Return first matching entity.
     ///
     /// # See also
@@ -906,10 +1125,12 @@ where
 
         let it = &mut self.retrieve_iter();
 
-        if self.iter_next(it) && it.count > 0 {
-            entity = Some(EntityView::new_from(self.world(), unsafe {
-                *it.entities.add(0)
-            }));
+        if self.iter_next(it) {
+            if it.count > 0 {
+                entity = Some(EntityView::new_from(self.world(), unsafe {
+                    *it.entities.add(0)
+                }));
+            }
             unsafe { sys::ecs_iter_fini(it) };
         }
         entity
@@ -926,6 +1147,54 @@ where
         result
     }
 
+    /// Test whether a table matches the query, without iterating the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table to test.
+    ///
+    /// # Returns
+    ///
+    /// True if the table matches the query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set(Position { x: 1, y: 2 });
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// assert!(query.matches_table(e.table().unwrap()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `query_base::has_table`
+    #[doc(alias = "query_base::has_table")]
+    fn matches_table(&self, table: impl IntoTable) -> bool {
+        let mut it = std::mem::MaybeUninit::<sys::ecs_iter_t>::uninit();
+        let result = unsafe {
+            sys::ecs_query_has_table(
+                self.query_ptr() as *mut _,
+                table.table_ptr_mut(),
+                it.as_mut_ptr(),
+            )
+        };
+        if result {
+            unsafe { sys::ecs_iter_fini(it.as_mut_ptr()) };
+        }
+        result
+    }
+
     /// Return total number of entities in result.
     ///
     /// # Returns
@@ -1081,3 +1350,25 @@ where
         func(EntityView::new_from(world, *(*iter).entities.add(i)), tuple);
     }
 }
+
+/// Finalizes an iterator handed to a `run` callback that returned (or panicked)
+/// before iterating it until completion.
+struct IterFini(*mut sys::ecs_iter_t);
+
+impl Drop for IterFini {
+    fn drop(&mut self) {
+        unsafe { fini_unfinished(&mut *self.0) };
+    }
+}
+
+/// Finalizes an iterator that is still in progress when a callback panics, so
+/// the world can still be cleaned up after the panic is caught.
+struct IterFiniOnUnwind(*mut sys::ecs_iter_t);
+
+impl Drop for IterFiniOnUnwind {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            unsafe { sys::ecs_iter_fini(self.0) };
+        }
+    }
+}
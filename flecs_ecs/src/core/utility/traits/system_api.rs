@@ -17,6 +17,58 @@ where
     #[doc(alias = "system_builder_i::ctx")]
     fn set_context(&mut self, context: *mut c_void) -> &mut Self;
 
+    /// Set a context that is owned by the system or observer.
+    ///
+    /// The context is dropped when the system or observer is deleted. It can be
+    /// accessed from the callbacks with [`TableIter::context()`]. Each system or
+    /// observer gets its own context, which makes this the place for per-instance
+    /// state, unlike the world context which is shared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let system = world
+    ///     .system::<&Position>()
+    ///     .set_context_boxed(Box::new(0u32))
+    ///     .run(|mut it| {
+    ///         // the context was set to a `u32` above
+    ///         let count = unsafe { it.context::<u32>() };
+    ///         while it.next() {
+    ///             *count += it.count() as u32;
+    ///         }
+    ///     });
+    ///
+    /// world.progress();
+    /// world.progress();
+    ///
+    /// let count = unsafe { *(system.context() as *const u32) };
+    /// assert_eq!(count, 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`SystemAPI::set_context()`]
+    /// * C++ API: `observer_builder_i::ctx`
+    /// * C++ API: `system_builder_i::ctx`
+    #[doc(alias = "observer_builder_i::ctx")]
+    #[doc(alias = "system_builder_i::ctx")]
+    fn set_context_boxed<C: 'static>(&mut self, context: Box<C>) -> &mut Self {
+        self.set_context(Box::into_raw(context) as *mut c_void);
+        self.set_context_free(Some(Self::free_callback::<C>));
+        self
+    }
+
     fn each<Func>(&mut self, func: Func) -> <Self as builder::Builder<'a>>::BuiltType
     where
         Func: FnMut(T::TupleType<'_>) + 'static,
@@ -578,6 +630,11 @@ macro_rules! implement_reactor_api {
                 self
             }
 
+            fn set_context_free(&mut self, ctx_free: sys::ecs_ctx_free_t) -> &mut Self {
+                self.desc.ctx_free = ctx_free;
+                self
+            }
+
             fn desc_binding_context(&self) -> *mut c_void {
                 self.desc.callback_ctx
             }
@@ -603,6 +660,7 @@ macro_rules! implement_reactor_api {
         {
             fn set_context(&mut self, context: *mut c_void) -> &mut Self {
                 self.desc.ctx = context;
+                self.desc.ctx_free = None;
                 self
             }
         }
@@ -643,6 +701,11 @@ macro_rules! implement_reactor_api {
                 self
             }
 
+            fn set_context_free(&mut self, ctx_free: sys::ecs_ctx_free_t) -> &mut Self {
+                self.desc.ctx_free = ctx_free;
+                self
+            }
+
             fn desc_binding_context(&self) -> *mut c_void {
                 self.desc.callback_ctx
             }
@@ -669,6 +732,7 @@ macro_rules! implement_reactor_api {
         {
             fn set_context(&mut self, context: *mut c_void) -> &mut Self {
                 self.desc.ctx = context;
+                self.desc.ctx_free = None;
                 self
             }
         }
@@ -127,3 +127,4 @@ impl ObserverEntityBindingCtx {
 
 pub struct ImplementsClone<T>(std::marker::PhantomData<T>);
 pub struct ImplementsDefault<T>(std::marker::PhantomData<T>);
+pub struct ImplementsMeta<T>(std::marker::PhantomData<T>);
@@ -15,6 +15,17 @@ use crate::sys;
 
 pub(crate) type FlecsArray = std::vec::Vec<u64>;
 
+/// Schema version of a world, stored as a singleton.
+///
+/// # See also
+///
+/// * [`World::set_version()`]
+/// * [`World::version()`]
+#[derive(flecs_ecs_derive::Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorldVersion {
+    pub value: u32,
+}
+
 /// The `World` is the container for all ECS data. It stores the entities and
 /// their components, does queries and runs systems.
 ///
@@ -57,33 +68,27 @@ unsafe impl Send for World {}
 
 impl Default for World {
     fn default() -> Self {
-        let raw_world = NonNull::new(unsafe { sys::ecs_init() }).unwrap();
-        let ctx = Box::leak(Box::new(WorldCtx::new()));
-        let components = unsafe { NonNull::new_unchecked(&mut ctx.components) };
-        let components_array = unsafe { NonNull::new_unchecked(&mut ctx.components_array) };
-        let world = Self {
-            raw_world,
-            components,
-            components_array,
-        };
-        unsafe {
-            sys::ecs_set_binding_ctx(
-                world.raw_world.as_ptr(),
-                ctx as *mut WorldCtx as *mut c_void,
-                None, //we manually destroy it in world drop for ref count check
-            );
-        }
-
-        world.init_builtin_components();
-        world
+        unsafe { Self::from_raw(sys::ecs_init()) }
     }
 }
 
 impl Drop for World {
     fn drop(&mut self) {
         let world_ptr = self.raw_world.as_ptr();
+        let is_stage = unsafe { sys::ecs_stage_get_id(world_ptr) } == -1;
+        // checked before the reference is released, so a panic leaves the world intact
+        if !is_stage
+            && !std::thread::panicking()
+            && unsafe { sys::flecs_poly_refcount(world_ptr as *mut c_void) } == 1
+        {
+            assert!(
+                !unsafe { sys::ecs_is_deferred(world_ptr) },
+                "cannot destroy the world while it is deferred, call defer_end first"
+            );
+        }
+
         if unsafe { sys::flecs_poly_release_(world_ptr as *mut c_void) } == 0 {
-            if unsafe { sys::ecs_stage_get_id(world_ptr) } == -1 {
+            if is_stage {
                 unsafe { sys::ecs_stage_free(world_ptr) };
             } else {
                 let ctx = self.world_ctx_mut();
@@ -108,6 +113,89 @@ impl World {
         Self::default()
     }
 
+    /// Take ownership of a raw world pointer.
+    ///
+    /// The returned `World` owns one reference to the world, which it releases when dropped.
+    /// The world is destroyed once all references are released. Worlds that were not created
+    /// through `flecs_ecs`, such as a world created by C code with `ecs_init`, are prepared
+    /// for use from Rust the first time they are passed to this function.
+    ///
+    /// # Safety
+    ///
+    /// `raw_world` must point to a valid world, not a stage, and the caller must own a
+    /// reference to it, for example one returned by [`World::into_raw()`] or the initial
+    /// reference of `ecs_init`. Ownership of that reference is transferred to the `World`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// let e = world.entity().id();
+    ///
+    /// let raw = world.into_raw();
+    /// let world = unsafe { World::from_raw(raw) };
+    /// assert!(world.is_alive(e));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::into_raw()`]
+    /// * [`WorldRef::from_ptr()`]
+    pub unsafe fn from_raw(raw_world: *mut sys::ecs_world_t) -> Self {
+        let raw_world = NonNull::new(raw_world).expect("world pointer is null");
+
+        let ctx = unsafe { sys::ecs_get_binding_ctx(raw_world.as_ptr()) } as *mut WorldCtx;
+        if !ctx.is_null() {
+            return Self {
+                raw_world,
+                components: unsafe { NonNull::new_unchecked(&mut (*ctx).components) },
+                components_array: unsafe { NonNull::new_unchecked(&mut (*ctx).components_array) },
+            };
+        }
+
+        let ctx = Box::leak(Box::new(WorldCtx::new()));
+        let components = unsafe { NonNull::new_unchecked(&mut ctx.components) };
+        let components_array = unsafe { NonNull::new_unchecked(&mut ctx.components_array) };
+        let world = Self {
+            raw_world,
+            components,
+            components_array,
+        };
+        unsafe {
+            sys::ecs_set_binding_ctx(
+                world.raw_world.as_ptr(),
+                ctx as *mut WorldCtx as *mut c_void,
+                None, //we manually destroy it in world drop for ref count check
+            );
+            sys::ecs_atfini(
+                world.raw_world.as_ptr(),
+                Some(WorldCtx::free_pending_post_frame),
+                ctx as *mut WorldCtx as *mut c_void,
+            );
+        }
+
+        world.init_builtin_components();
+        world
+    }
+
+    /// Relinquish ownership of the world and return the raw world pointer.
+    ///
+    /// The reference owned by this `World` is not released, so the world stays alive until
+    /// the pointer is passed back to [`World::from_raw()`] and dropped, or is destroyed with
+    /// `ecs_fini` by C code. Other `World` handles are unaffected.
+    ///
+    /// # See also
+    ///
+    /// * [`World::from_raw()`]
+    /// * [`World::ptr_mut()`]
+    pub fn into_raw(self) -> *mut sys::ecs_world_t {
+        let raw_world = self.raw_world.as_ptr();
+        std::mem::forget(self);
+        raw_world
+    }
+
     fn init_builtin_components(&self) {
         // used for event handling with no data
         self.component_named::<()>("flecs::rust::() - None");
@@ -428,6 +516,7 @@ impl World {
     ///
     /// # See also
     ///
+    /// * [`World::readonly()`]
     /// * [`World::readonly_end()`]
     /// * C++ API: `world::readonly_begin`
     #[doc(alias = "world::readonly_begin")]
@@ -464,6 +553,51 @@ impl World {
         }
     }
 
+    /// Runs the passed-in closure with the world in readonly mode.
+    ///
+    /// Calls [`World::readonly_begin()`] before and [`World::readonly_end()`] after
+    /// the closure. Operations done through a stage in the closure are enqueued and
+    /// merged back into the world when readonly mode ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_threaded` - Whether the stages will be accessed from multiple threads.
+    /// * `func` - The closure to execute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let stage = world.stage(0);
+    ///
+    /// world.readonly(false, || {
+    ///     stage.entity().set(Position { x: 10, y: 20 });
+    ///     assert_eq!(world.count::<Position>(), 0);
+    /// });
+    ///
+    /// assert_eq!(world.count::<Position>(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::readonly_begin()`]
+    /// * [`World::readonly_end()`]
+    /// * [`World::is_readonly()`]
+    pub fn readonly<T>(&self, multi_threaded: bool, func: impl FnOnce() -> T) -> T {
+        self.readonly_begin(multi_threaded);
+        let result = func();
+        self.readonly_end();
+        result
+    }
+
     /// Test whether the current world object is readonly.
     ///
     /// This function allows the code to test whether the currently used world
@@ -507,6 +641,9 @@ impl World {
     /// [`World::defer_begin()`] and [`World::defer_end()`] are executed at the
     /// end of the frame.
     ///
+    /// Every call must be matched by [`World::defer_end()`]. Dropping the last
+    /// handle to a world that is still deferred panics.
+    ///
     /// # Safety
     /// This operation is thread safe.
     ///
@@ -558,6 +695,11 @@ impl World {
     ///
     /// This should follow a [`World::defer_begin()`] call.
     ///
+    /// # Panics
+    ///
+    /// Panics with [`FlecsErrorCode::InvalidOperation`] if the world is not deferred, which
+    /// happens when there is no matching call to [`World::defer_begin()`].
+    ///
     /// # Safety
     /// This operation is thread safe.
     ///
@@ -578,6 +720,11 @@ impl World {
     /// * C++ API: `world::defer_end`
     #[doc(alias = "world::defer_end")]
     pub fn defer_end(&self) -> bool {
+        ecs_assert!(
+            self.is_deferred(),
+            FlecsErrorCode::InvalidOperation,
+            "defer_end called without a matching defer_begin"
+        );
         unsafe { sys::ecs_defer_end(self.raw_world.as_ptr()) }
     }
 
@@ -776,6 +923,7 @@ impl World {
     /// * [`World::stage_id()`]
     /// * C++ API: `world::get_stage_count`
     #[doc(alias = "world::get_stage_count")]
+    #[doc(alias = "stage_count")]
     pub fn get_stage_count(&self) -> i32 {
         unsafe { sys::ecs_get_stage_count(self.raw_world.as_ptr()) }
     }
@@ -1088,9 +1236,131 @@ impl World {
     #[doc(alias = "world::set_ctx")]
     #[allow(clippy::not_unsafe_ptr_arg_deref)] // this doesn't actually deref the pointer
     pub fn set_context(&self, ctx: *mut c_void, ctx_free: sys::ecs_ctx_free_t) {
+        self.free_boxed_ctx();
         unsafe { sys::ecs_set_ctx(self.raw_world.as_ptr(), ctx, ctx_free) }
     }
 
+    /// Set world context to a boxed value.
+    ///
+    /// The world takes ownership of the value and drops it when the world is destroyed
+    /// or when the context is replaced. The value can be accessed with [`World::with_ctx()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The world context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context is replaced from inside [`World::with_ctx()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// struct Settings {
+    ///     gravity: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_ctx(Box::new(Settings { gravity: 9.81 }));
+    ///
+    /// assert_eq!(world.with_ctx(|s: &Settings| s.gravity), Some(9.81));
+    /// assert!(world.with_ctx(|_: &i32| ()).is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::with_ctx()`]
+    /// * [`World::set_context()`]
+    /// * C++ API: `world::set_ctx`
+    #[doc(alias = "world::set_ctx")]
+    pub fn set_ctx<T: Send + Sync + 'static>(&self, ctx: Box<T>) {
+        unsafe extern "C" fn free_ctx(ctx: *mut c_void) {
+            drop(unsafe { Box::from_raw(ctx as *mut Box<dyn std::any::Any>) });
+        }
+
+        self.free_boxed_ctx();
+        let ctx: Box<Box<dyn std::any::Any>> = Box::new(ctx);
+        unsafe {
+            sys::ecs_set_ctx(
+                self.raw_world.as_ptr(),
+                Box::into_raw(ctx) as *mut c_void,
+                Some(free_ctx),
+            );
+        }
+        self.world_ctx_mut().has_boxed_ctx = true;
+    }
+
+    /// Access the world context set by [`World::set_ctx()`].
+    ///
+    /// The context is only borrowed for the duration of the callback. Replacing the context
+    /// while it is borrowed panics, as that would drop the value the callback refers to.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the context.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The callback invoked with the context.
+    ///
+    /// # Returns
+    ///
+    /// The result of the callback, or `None` if no context was set with [`World::set_ctx()`]
+    /// or the context is not of type `T`.
+    ///
+    /// # Example
+    ///
+    /// See [`World::set_ctx`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_ctx()`]
+    /// * C++ API: `world::get_ctx`
+    #[doc(alias = "world::get_ctx")]
+    pub fn with_ctx<T: 'static, R>(&self, func: impl FnOnce(&T) -> R) -> Option<R> {
+        struct BorrowGuard<'a>(&'a std::sync::atomic::AtomicUsize);
+
+        impl Drop for BorrowGuard<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, std::sync::atomic::Ordering::AcqRel);
+            }
+        }
+
+        if !self.world_ctx().has_boxed_ctx {
+            return None;
+        }
+        let ctx =
+            unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) } as *const Box<dyn std::any::Any>;
+        let ctx = unsafe { (*ctx).downcast_ref::<T>() }?;
+
+        let borrows = &self.world_ctx().ctx_borrows;
+        borrows.fetch_add(1, std::sync::atomic::Ordering::AcqRel);
+        let _guard = BorrowGuard(borrows);
+        Some(func(ctx))
+    }
+
+    fn free_boxed_ctx(&self) {
+        let world_ctx = self.world_ctx_mut();
+        assert!(
+            world_ctx
+                .ctx_borrows
+                .load(std::sync::atomic::Ordering::Acquire)
+                == 0,
+            "{}: the world context can't be replaced while it is borrowed by `with_ctx`",
+            FlecsErrorCode::InvalidOperation
+        );
+        if world_ctx.has_boxed_ctx {
+            world_ctx.has_boxed_ctx = false;
+            let world = self.raw_world.as_ptr();
+            let ctx = unsafe { sys::ecs_get_ctx(world) };
+            unsafe { sys::ecs_set_ctx(world, std::ptr::null_mut(), None) };
+            drop(unsafe { Box::from_raw(ctx as *mut Box<dyn std::any::Any>) });
+        }
+    }
+
     /// Get world context.
     ///
     /// # Returns
@@ -1110,6 +1380,66 @@ impl World {
         unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) }
     }
 
+    /// Set the schema version of the world.
+    ///
+    /// The version is stored in the [`WorldVersion`] singleton, so it is saved
+    /// and restored together with the rest of the world data. Save/load code
+    /// can use it to detect and migrate data written by older versions.
+    ///
+    /// [`WorldVersion`] is registered with reflection data, but lives in the
+    /// `flecs_ecs` module scope, so serializers must include modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The schema version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// assert_eq!(world.version(), None);
+    ///
+    /// world.set_version(2);
+    /// assert_eq!(world.version(), Some(2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::version()`]
+    pub fn set_version(&self, version: u32) {
+        self.version_component();
+        self.set(WorldVersion { value: version });
+    }
+
+    /// Get the schema version of the world.
+    ///
+    /// # Returns
+    ///
+    /// The version set with [`World::set_version()`], or `None` if no version
+    /// was set.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_version()`]
+    pub fn version(&self) -> Option<u32> {
+        self.version_component();
+        self.try_map::<&WorldVersion, _>(|version| Some(version.value))
+    }
+
+    /// Register [`WorldVersion`], including its reflection data so that it can
+    /// be serialized.
+    fn version_component(&self) {
+        let component = self.component::<WorldVersion>();
+        #[cfg(feature = "flecs_meta")]
+        if !component.has::<flecs::meta::StructT>() {
+            component.member::<u32>("value");
+        }
+        #[cfg(not(feature = "flecs_meta"))]
+        let _ = component;
+    }
+
     pub(crate) fn get_context(world: *mut sys::ecs_world_t) -> *mut WorldCtx {
         unsafe { sys::ecs_get_binding_ctx(world) as *mut WorldCtx }
     }
@@ -1386,7 +1716,9 @@ impl World {
 
     /// Sets the search path for entity lookup operations.
     ///
-    /// This function configures the search path used for looking up an entity.
+    /// When a name can't be found relative to the current scope, recursive lookups such as
+    /// [`World::lookup_recursive()`] search the scopes of the lookup path, similar to a
+    /// `using` declaration. The scopes are searched starting from the last element.
     ///
     /// # Best Practices
     ///
@@ -1406,11 +1738,30 @@ impl World {
     ///
     /// # Arguments
     ///
-    /// * `search_path` - The entity to set as the search path.
+    /// * `search_path` - The scopes to search, the last scope is searched first.
     ///
     /// # Returns
     ///
-    /// Returns the current search path after the operation.
+    /// The previous search path, which can be passed to this function to restore it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let game = world.entity_named("game");
+    /// let foo = world.entity_named("Foo").child_of_id(game);
+    ///
+    /// assert!(world.try_lookup_recursive("Foo").is_none());
+    ///
+    /// let previous = world.set_lookup_path([game]);
+    /// assert_eq!(world.lookup_recursive("Foo"), foo);
+    ///
+    /// world.set_lookup_path(previous);
+    /// assert!(world.try_lookup_recursive("Foo").is_none());
+    /// ```
     ///
     /// # See also
     ///
@@ -1421,11 +1772,28 @@ impl World {
     /// * C++ API: `world::set_lookup_path`
     /// * C API: `sys::ecs_set_lookup_path`
     #[doc(alias = "world::set_lookup_path")]
-    #[doc(alias = "wsys::ecs_set_lookup_path")]
-    #[allow(clippy::not_unsafe_ptr_arg_deref)] // this doesn't actually deref the pointer
-                                               // TODO we need to improve this function somehow, it's not very ergonomic
-    pub fn set_lookup_path(&self, search_path: impl Into<Entity>) -> *mut sys::ecs_entity_t {
-        unsafe { sys::ecs_set_lookup_path(self.raw_world.as_ptr(), &*search_path.into()) }
+    #[doc(alias = "sys::ecs_set_lookup_path")]
+    pub fn set_lookup_path(
+        &self,
+        search_path: impl IntoIterator<Item = impl Into<Entity>>,
+    ) -> Vec<Entity> {
+        // flecs doesn't copy the path, so it's kept alive in the world context.
+        // The path is terminated with a 0.
+        let mut path: Vec<u64> = search_path.into_iter().map(|e| *e.into()).collect();
+        path.push(0);
+
+        let world_ctx = self.world_ctx_mut();
+        let previous = unsafe {
+            let mut cur = sys::ecs_set_lookup_path(self.raw_world.as_ptr(), path.as_ptr());
+            let mut previous = Vec::new();
+            while !cur.is_null() && *cur != 0 {
+                previous.push(Entity::new(*cur));
+                cur = cur.add(1);
+            }
+            previous
+        };
+        world_ctx.lookup_path = path;
+        previous
     }
 
     /// Lookup an entity by name.
@@ -1505,6 +1873,40 @@ impl World {
             .expect("Entity not found, when unsure, use try_lookup")
     }
 
+    /// Get the name of an entity.
+    ///
+    /// This is the reverse of [`World::lookup()`]. Names are stored in the
+    /// `(Identifier, Name)` component, so this is a direct component lookup
+    /// that always reflects renames and does not need a separate cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to get the name of.
+    ///
+    /// # Returns
+    ///
+    /// The name of the entity, or `None` if the entity has no name or is not alive.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_name()`]
+    /// * [`World::lookup()`]
+    /// * C++ API: `entity_view::name`
+    #[doc(alias = "entity_view::name")]
+    pub fn name_of(&self, entity: impl Into<Entity>) -> Option<&str> {
+        let entity = *entity.into();
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        let name = unsafe { sys::ecs_get_name(self.raw_world.as_ptr(), entity) };
+        if name.is_null() {
+            None
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(name) }.to_str().ok()
+        }
+    }
+
     /// Helper function for [`World::try_lookup()`] and [`World::try_lookup_recursive()`].
     fn try_lookup_impl(&self, name: &str, recursively: bool) -> Option<EntityView> {
         let name = compact_str::format_compact!("{}\0", name);
@@ -1574,19 +1976,104 @@ impl World {
         self.try_lookup_impl(name, false)
     }
 
-    /// Sets a singleton component of type `T` on the world.
+    /// Lookup an entity by its symbol.
+    ///
+    /// Components are registered with a symbol that is derived from their Rust type name,
+    /// independent of the scope or name they are registered under. This makes the symbol
+    /// useful to find the same component across modules.
+    ///
+    /// # Panics
+    ///
+    /// Ensure that an entity with the symbol exists before using it.
+    /// Use the [`World::try_lookup_symbol()`] variant otherwise.
     ///
     /// # Arguments
     ///
-    /// * `component` - The singleton component to set on the world.
+    /// * `symbol` - The symbol of the entity to lookup.
+    ///
+    /// # Returns
+    ///
+    /// The entity
     ///
     /// # See also
     ///
-    /// * C++ API: `world::set`
-    #[doc(alias = "world::set")]
-    pub fn set<T: ComponentId + DataComponent + ComponentType<Struct>>(&self, component: T) {
-        let id = T::id(self);
-        set_helper(self.raw_world.as_ptr(), id, component, id);
+    /// * [`EntityView::symbol()`]
+    /// * [`World::lookup()`]
+    /// * [`World::try_lookup_symbol()`]
+    /// * C API: `ecs_lookup_symbol`
+    #[doc(alias = "ecs_lookup_symbol")]
+    #[inline(always)]
+    pub fn lookup_symbol(&self, symbol: &str) -> EntityView<'_> {
+        self.try_lookup_symbol(symbol)
+            .expect("Entity not found, when unsure, use try_lookup_symbol")
+    }
+
+    /// Lookup an entity by its symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The symbol of the entity to lookup.
+    ///
+    /// # Returns
+    ///
+    /// The entity if found, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let pos = world.component::<Position>();
+    /// assert_eq!(world.try_lookup_symbol(pos.symbol()).unwrap(), pos.id());
+    /// assert!(world.try_lookup_symbol("NotASymbol").is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::symbol()`]
+    /// * [`World::lookup_symbol()`]
+    /// * [`World::try_lookup()`]
+    /// * C API: `ecs_lookup_symbol`
+    #[doc(alias = "ecs_lookup_symbol")]
+    pub fn try_lookup_symbol(&self, symbol: &str) -> Option<EntityView<'_>> {
+        let symbol = compact_str::format_compact!("{}\0", symbol);
+
+        let entity_id = unsafe {
+            sys::ecs_lookup_symbol(
+                self.raw_world.as_ptr(),
+                symbol.as_ptr() as *const _,
+                false,
+                false,
+            )
+        };
+        if entity_id == 0 {
+            None
+        } else {
+            Some(EntityView::new_from(self, entity_id))
+        }
+    }
+
+    /// Sets a singleton component of type `T` on the world.
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - The singleton component to set on the world.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `world::set`
+    #[doc(alias = "world::set")]
+    pub fn set<T: ComponentId + DataComponent + ComponentType<Struct>>(&self, component: T) {
+        let id = T::id(self);
+        set_helper(self.raw_world.as_ptr(), id, component, id);
     }
 
     /// Set a singleton pair using the second element type and a first id.
@@ -1820,6 +2307,67 @@ impl World {
         entity.get::<T>(callback);
     }
 
+    /// Gets mutable access to a singleton component or relationship and signals that it was modified.
+    ///
+    /// This is the mutable counterpart of [`World::get`] for singletons: the callback receives
+    /// a mutable reference to the component stored on its own component entity, after which
+    /// the component is flagged as modified, so `OnSet` observers and change detection
+    /// are notified.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component or pair to get.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback receiving the mutable singleton.
+    ///
+    /// # Panics
+    ///
+    /// - This will panic if the world does not have the singleton component.
+    ///   Use [`World::try_get`] with `&mut T` if unsure, and call [`World::modified`] yourself.
+    /// - Same as [`World::get`], this will panic if within the callback you do any operation
+    ///   that could invalidate the reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// pub struct Config {
+    ///     pub volume: u32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set(Config { volume: 5 });
+    ///
+    /// world
+    ///     .observer::<flecs::OnSet, &Config>()
+    ///     .each(|config| {
+    ///         assert_eq!(config.volume, 10);
+    ///     });
+    ///
+    /// world.get_singleton_mut::<Config>(|config| {
+    ///     config.volume = 10;
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::get()`]
+    /// * [`World::modified()`]
+    /// * [`World::singleton()`]
+    pub fn get_singleton_mut<T>(&self, callback: impl FnOnce(&mut T::CastType))
+    where
+        T: ComponentOrPairId + DataComponent,
+    {
+        let entity = EntityView::new_from(self, <T::CastType as ComponentId>::id(self));
+        entity.get::<&mut T>(callback);
+        entity.modified::<T>();
+    }
+
     /// Clones a singleton component and/or relationship from the world and returns it.
     /// each component type must be marked `&`. This helps Rust type checker to determine if it's a relationship.
     /// use `Option` wrapper to indicate if the component is optional.
@@ -3283,23 +3831,144 @@ impl World {
         EntityView::new_from(self, entity)
     }
 
+    /// Override the generation of an entity.
+    ///
+    /// Entity ids are recycled after an entity is deleted, and every time an id is recycled its
+    /// generation is increased. This operation replaces the generation of the entity, which lets
+    /// tests and snapshots rely on exact entity ids regardless of how often an id was recycled.
+    /// If the entity is not alive, it is made alive with the provided generation.
+    ///
+    /// This operation is intended for tests and tooling. Any handle to the entity that still
+    /// holds the old generation is no longer valid afterwards.
+    ///
+    /// This operation is not allowed while the world is deferred or in readonly mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to change. Its current generation is ignored.
+    /// * `generation` - The new generation. Only the lower 16 bits are used.
+    ///
+    /// # Returns
+    ///
+    /// The entity with the provided generation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e1 = world.entity();
+    /// let id = e1.id();
+    /// e1.destruct();
+    ///
+    /// // The id is recycled with an increased generation.
+    /// let e2 = world.entity();
+    /// assert_ne!(e2.id(), id);
+    ///
+    /// let e2 = world.set_entity_generation(e2, 0);
+    /// assert_eq!(e2.id(), id);
+    /// assert!(e2.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::make_alive()`]
+    /// * C++ API: `world::set_version`
+    #[doc(alias = "world::set_version")]
+    /// * C API: `ecs_set_version`
+    #[doc(alias = "ecs_set_version")]
+    pub fn set_entity_generation(
+        &self,
+        entity: impl Into<Entity>,
+        generation: u32,
+    ) -> EntityView<'_> {
+        let entity =
+            (*entity.into() & !sys::ECS_GENERATION_MASK) | (((generation & 0xFFFF) as u64) << 32);
+        unsafe { sys::ecs_set_version(self.raw_world.as_ptr(), entity) };
+        EntityView::new_from(self, entity)
+    }
+
     /// Run callback after completing frame
     ///
+    /// The callback runs once at the end of the current frame, after all systems
+    /// have run and deferred operations have been merged. Callbacks registered in
+    /// the same frame run in the order they were registered.
+    ///
+    /// This operation may only be called while a frame is in progress, e.g. from
+    /// within a system. If the world is destroyed before the frame ends, the callback
+    /// is dropped without running.
+    ///
     /// # Arguments
     ///
-    /// * `action` - The action to run.
-    /// * `ctx` - The context to pass to the action.
+    /// * `func` - The callback to run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let world = World::new();
+    ///
+    /// let ran = Rc::new(Cell::new(false));
+    /// let ran_clone = ran.clone();
+    ///
+    /// world.system::<()>().run(move |it| {
+    ///     let ran = ran_clone.clone();
+    ///     it.world().run_post_frame(move |_world| ran.set(true));
+    /// });
+    ///
+    /// world.progress();
+    /// assert!(ran.get());
+    /// ```
     ///
     /// # See also
     ///
     /// * C++ API: `world::run_post_frame`
     #[doc(alias = "world::run_post_frame")]
-    #[allow(clippy::not_unsafe_ptr_arg_deref)] // this doesn't actually deref the pointer
-    pub fn run_post_frame(&self, action: sys::ecs_fini_action_t, ctx: *mut c_void) {
+    pub fn run_post_frame<F>(&self, func: F)
+    where
+        F: FnOnce(WorldRef) + 'static,
+    {
+        let ctx = Box::into_raw(Box::new(func)) as *mut c_void;
+        self.world_ctx()
+            .pending_post_frame
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(PendingPostFrame {
+                ctx,
+                free: Self::free_post_frame_action::<F>,
+            });
         unsafe {
-            sys::ecs_run_post_frame(self.raw_world.as_ptr(), action, ctx);
+            sys::ecs_run_post_frame(
+                self.raw_world.as_ptr(),
+                Some(Self::run_post_frame_action::<F>),
+                ctx,
+            );
         }
     }
+
+    unsafe extern "C" fn run_post_frame_action<F>(world: *mut sys::ecs_world_t, ctx: *mut c_void)
+    where
+        F: FnOnce(WorldRef) + 'static,
+    {
+        let world = unsafe { WorldRef::from_ptr(world) };
+        world
+            .world_ctx()
+            .pending_post_frame
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|action| action.ctx != ctx);
+        let func = unsafe { Box::from_raw(ctx as *mut F) };
+        func(world);
+    }
+
+    unsafe fn free_post_frame_action<F>(ctx: *mut c_void) {
+        drop(unsafe { Box::from_raw(ctx as *mut F) });
+    }
 }
 
 /// `EntityView` mixin implementation
@@ -3734,6 +4403,42 @@ impl World {
         Component::<T::UnderlyingType>::new_named(self, name)
     }
 
+    /// Check whether a component is registered with the world, without registering it.
+    ///
+    /// Unlike [`World::component()`] or [`World::component_id()`], this never registers the
+    /// component as a side effect.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component type.
+    ///
+    /// # Returns
+    ///
+    /// True if the component is registered with this world, false otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// assert!(!world.component_registered::<Position>());
+    ///
+    /// world.component::<Position>();
+    ///
+    /// assert!(world.component_registered::<Position>());
+    /// ```
+    pub fn component_registered<T: ComponentId>(&self) -> bool {
+        T::UnderlyingType::is_registered_with_world(self)
+    }
+
     /// Find or register untyped component.
     ///
     /// # Type Parameters
@@ -4082,6 +4787,67 @@ impl World {
             .expect("entity / query is not alive or valid")
     }
 
+    /// Look up a named query by name.
+    ///
+    /// Named queries are created with [`World::query_named()`] or
+    /// [`World::new_query_named()`], and can be looked up by other modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the query.
+    ///
+    /// # Returns
+    ///
+    /// The untyped query if an entity with the name exists and is a query, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.query_named::<&Position>("movers").build();
+    ///
+    /// assert!(world.try_query_lookup("movers").is_some());
+    /// assert!(world.try_query_lookup("jumpers").is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::query_lookup()`]
+    /// * [`World::query_named()`]
+    /// * [`World::try_query_from()`]
+    pub fn try_query_lookup(&self, name: &str) -> Option<Query<()>> {
+        self.try_lookup(name)
+            .and_then(|entity| self.try_query_from(entity))
+    }
+
+    /// Look up a named query by name.
+    /// this method is the same as `try_query_lookup` but it automatically unwraps the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no query with the name exists. Use `try_query_lookup` if you are unsure.
+    ///
+    /// # See also
+    ///
+    /// * [`World::try_query_lookup()`]
+    /// * [`World::query_named()`]
+    pub fn query_lookup(&self, name: &str) -> Query<()> {
+        self.try_query_lookup(name)
+            .expect("no query with this name exists")
+    }
+
     /// Create and iterate an uncached query.
     ///
     /// This function creates a query and immediately iterates it.
@@ -4111,7 +4877,10 @@ impl World {
 
     /// Create and iterate an uncached query.
     ///
-    /// This function creates a query and immediately iterates it.
+    /// This function creates a query and immediately iterates it, passing the entity
+    /// together with its components. Components inherited from a base entity are matched
+    /// the same way as with [`QueryAPI::each_entity()`]. The returned query is freed when
+    /// it is dropped, so the result can be ignored for one-shot iteration.
     ///
     /// # Returns
     ///
@@ -4121,6 +4890,27 @@ impl World {
     ///
     /// * `Components`: The components to match on.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity().set(Position { x: 1, y: 2 });
+    ///
+    /// world.each_entity::<&mut Position>(|_entity, pos| {
+    ///     pos.x += 10;
+    /// });
+    ///
+    /// e.get::<&Position>(|pos| assert_eq!(pos.x, 11));
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`QueryAPI::each_entity()`]
@@ -4138,6 +4928,181 @@ impl World {
         query.each_entity(func);
         query
     }
+
+    /// Create and iterate an uncached query, restricted to the descendants of a parent.
+    ///
+    /// The query matches entities with a `(ChildOf, parent)` pair on themselves or on one
+    /// of their ancestors, so the whole subtree below `parent` is iterated. The parent
+    /// itself is not matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent`: The root of the subtree.
+    /// * `func`: The callback invoked with each matched entity and its components.
+    ///
+    /// # Returns
+    ///
+    /// The query.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Components`: The components to match on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Visible(bool);
+    ///
+    /// let world = World::new();
+    ///
+    /// let panel = world.entity();
+    /// let button = world.entity().child_of_id(panel).set(Visible(true));
+    /// let label = world.entity().child_of_id(button).set(Visible(true));
+    /// let other = world.entity().set(Visible(true));
+    ///
+    /// world.each_in_subtree::<&mut Visible>(panel, |_entity, visible| {
+    ///     visible.0 = false;
+    /// });
+    ///
+    /// button.get::<&Visible>(|v| assert!(!v.0));
+    /// label.get::<&Visible>(|v| assert!(!v.0));
+    /// other.get::<&Visible>(|v| assert!(v.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::each_entity()`]
+    /// * [`EntityView::each_child()`]
+    pub fn each_in_subtree<Components>(
+        &self,
+        parent: impl Into<Entity>,
+        func: impl FnMut(EntityView, Components::TupleType<'_>),
+    ) -> Query<Components>
+    where
+        Components: QueryTuple,
+    {
+        let query = QueryBuilder::<Components>::new(self)
+            .with_id((flecs::ChildOf::ID, parent.into()))
+            .self_()
+            .up()
+            .build();
+        query.each_entity(func);
+        query
+    }
+
+    /// Create and iterate an uncached query from a query expression.
+    ///
+    /// This function parses `expr` with the flecs query DSL, creates the query and immediately
+    /// iterates it, invoking `func` once for every matched table. Unlike [`World::each()`], an invalid expression does not panic.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr`: The query expression, e.g. `"Position, Velocity"`.
+    /// * `func`: The callback invoked with the iterator of each table.
+    ///
+    /// # Returns
+    ///
+    /// The query, or `None` if the expression could not be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// // Components are looked up by their full path.
+    /// let expr = world.component::<Position>().path_w_sep(".", "").unwrap();
+    ///
+    /// let mut count = 0;
+    /// world
+    ///     .each_expr(&expr, |it| {
+    ///         let p = it.field::<Position>(0).unwrap();
+    ///         count += p.len();
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(count, 1);
+    ///
+    /// assert!(world.each_expr("Position,, (", |_| {}).is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::each()`]
+    /// * [`QueryBuilderImpl::expr()`]
+    /// * C++ API: `world::query`
+    #[doc(alias = "world::query")]
+    pub fn each_expr(
+        &self,
+        expr: &str,
+        mut func: impl FnMut(TableIter<false, ()>),
+    ) -> Option<Query<()>> {
+        let query = QueryBuilder::<()>::new(self).expr(expr).try_build()?;
+        query.run_iter(|it, _| func(it));
+        Some(query)
+    }
+
+    /// Iterate all instances of a relationship in the world.
+    ///
+    /// This function creates an uncached `(First, *)` query and invokes the callback once for
+    /// every subject and target combination of the relationship.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First`: The relationship to iterate.
+    ///
+    /// # Arguments
+    ///
+    /// * `func`: The callback invoked with the subject and the target of each pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    ///
+    /// let alice = world.entity_named("Alice");
+    /// let bob = world.entity_named("Bob").add_first::<Likes>(alice);
+    ///
+    /// world.each_pair::<Likes>(|subject, target| {
+    ///     assert_eq!(subject, bob);
+    ///     assert_eq!(target, alice);
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_target()`]
+    /// * [`World::each_entity()`]
+    pub fn each_pair<First: ComponentId>(&self, mut func: impl FnMut(EntityView, EntityView)) {
+        let query = QueryBuilder::<()>::new(self)
+            .with_id((First::id(self), ECS_WILDCARD))
+            .build();
+
+        query.run(|mut it| {
+            while it.next() {
+                let pair = it.id(0);
+                let target = pair.second_id();
+                for i in it.iter() {
+                    func(it.entity(i), target);
+                }
+            }
+        });
+    }
 }
 
 /// Systems mixin implementation
@@ -4226,11 +5191,177 @@ impl World {
     {
         SystemBuilder::<Components>::new_from_desc(self, desc)
     }
+
+    /// Run a system manually, outside of the pipeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The system to run.
+    /// * `delta_time` - The time passed since the last system invocation.
+    /// * `param` - A user-defined parameter to pass to the system.
+    ///
+    /// # Returns
+    ///
+    /// The entity the system set with [`TableIter::set_interrupted_by()`], if any.
+    ///
+    /// # See also
+    ///
+    /// * [`System::run_dt_param()`]
+    /// * C++ API: `system::run`
+    #[doc(alias = "system::run")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)] // param is only forwarded to the system
+    pub fn run_system(
+        &self,
+        system: impl Into<Entity>,
+        delta_time: FTime,
+        param: *mut std::ffi::c_void,
+    ) -> Option<EntityView<'_>> {
+        let entity =
+            unsafe { sys::ecs_run(self.raw_world.as_ptr(), *system.into(), delta_time, param) };
+        if entity == 0 {
+            None
+        } else {
+            Some(EntityView::new_from(self, entity))
+        }
+    }
+
+    /// Run a system manually with a typed parameter.
+    ///
+    /// The system can access the parameter with [`TableIter::param_as()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The system to run.
+    /// * `delta_time` - The time passed since the last system invocation.
+    /// * `param` - The parameter to pass to the system.
+    ///
+    /// # Returns
+    ///
+    /// The entity the system set with [`TableIter::set_interrupted_by()`], if any.
+    ///
+    /// # See also
+    ///
+    /// * [`World::run_system()`]
+    /// * [`TableIter::param_as()`]
+    /// * C++ API: `system::run`
+    #[doc(alias = "system::run")]
+    pub fn run_system_with_param<T>(
+        &self,
+        system: impl Into<Entity>,
+        delta_time: FTime,
+        param: &T,
+    ) -> Option<EntityView<'_>> {
+        self.run_system(
+            system,
+            delta_time,
+            param as *const T as *mut std::ffi::c_void,
+        )
+    }
 }
 
 /// Pipeline mixin implementation
 #[cfg(feature = "flecs_pipeline")]
 impl World {
+    /// Set a callback that reports the time spent in each system.
+    ///
+    /// After every [`World::progress()`] or [`World::run_pipeline()`] the callback is invoked
+    /// once for each system that ran, in order of the system entity ids, with the name of the
+    /// system and the time it took in microseconds. Unnamed systems are reported by their id,
+    /// see [`EntityView::display_name()`]. Frames run by `App::run()` are not reported, as
+    /// the app advances the world from C without going through [`World::progress()`].
+    ///
+    /// Setting a profiler enables system time measurements, which adds a small overhead to
+    /// every system invocation. Only one profiler can be set at a time, setting a new one
+    /// replaces the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `profiler` - The callback receiving the system name and elapsed microseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.system_named::<()>("Physics").run(|mut it| while it.next() {});
+    ///
+    /// let timings = Arc::new(Mutex::new(Vec::new()));
+    /// let report = timings.clone();
+    /// world.set_system_profiler(move |name, micros| {
+    ///     report.lock().unwrap().push((name.to_string(), micros));
+    /// });
+    ///
+    /// world.progress();
+    ///
+    /// assert!(timings.lock().unwrap().iter().any(|(name, _)| name == "Physics"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::remove_system_profiler()`]
+    /// * C API: `ecs_measure_system_time`
+    pub fn set_system_profiler(&self, profiler: impl Fn(&str, f32) + Send + Sync + 'static) {
+        unsafe { sys::ecs_measure_system_time(self.raw_world.as_ptr(), true) };
+        let ctx = self.world_ctx_mut();
+        ctx.system_profiler = Some(std::sync::Arc::new(profiler));
+        ctx.system_time_spent.clear();
+    }
+
+    /// Remove the callback set by [`World::set_system_profiler()`] and disable system time
+    /// measurements.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_system_profiler()`]
+    pub fn remove_system_profiler(&self) {
+        unsafe { sys::ecs_measure_system_time(self.raw_world.as_ptr(), false) };
+        let ctx = self.world_ctx_mut();
+        ctx.system_profiler = None;
+        ctx.system_time_spent.clear();
+    }
+
+    /// Report the systems that ran since the last report to the system profiler, if one is set.
+    fn report_system_profile(&self) {
+        if self.world_ctx().system_profiler.is_none() {
+            return;
+        }
+
+        let world_ptr = self.raw_world.as_ptr();
+        let mut systems = Vec::new();
+        self.query::<()>()
+            .with::<(flecs::Poly, flecs::system::System)>()
+            .build()
+            .each_entity(|e, _| {
+                let system = unsafe { sys::ecs_system_get(world_ptr, *e.id()) };
+                if !system.is_null() {
+                    let system = unsafe { &*system };
+                    systems.push((e.id(), system.last_frame, system.time_spent));
+                }
+            });
+
+        let ctx = self.world_ctx_mut();
+        let Some(profiler) = ctx.system_profiler.clone() else {
+            return;
+        };
+        let mut timings = Vec::new();
+        for (id, last_frame, time_spent) in systems {
+            // the pipeline updates `last_frame` every time it runs a system
+            let previous = ctx.system_time_spent.insert(*id, (last_frame, time_spent));
+            let (previous_frame, previous_time) = previous.unwrap_or((0, 0.0));
+            if last_frame != previous_frame {
+                let name = EntityView::new_from(self, id).display_name();
+                timings.push((name, (time_spent - previous_time) * 1_000_000.0));
+            }
+        }
+
+        for (name, micros) in timings {
+            profiler(&name, micros);
+        }
+    }
+
     /// Create a new [`Pipeline`](crate::addons::pipeline::Pipeline).
     ///
     /// # See also
@@ -4391,7 +5522,10 @@ impl World {
     #[doc(alias = "world::progress")]
     #[inline(always)]
     pub fn progress_time(&self, delta_time: f32) -> bool {
-        unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) }
+        let result = unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) };
+        #[cfg(feature = "flecs_pipeline")]
+        self.report_system_profile();
+        result
     }
 
     /// Run pipeline.
@@ -4453,6 +5587,7 @@ impl World {
         unsafe {
             sys::ecs_run_pipeline(self.raw_world.as_ptr(), *pipeline.into(), delta_time);
         }
+        self.report_system_profile();
     }
 
     /// Run pipeline.
@@ -4712,11 +5847,26 @@ impl World {
 
     /// Delete empty tables within the world
     ///
+    /// Every call increases the generation of the empty tables it evaluates. A table that
+    /// becomes non-empty again has its generation reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Only evaluate tables with this (component) id, or 0 for all tables.
+    /// * `clear_generation` - Free table data when the generation exceeds this value.
+    /// * `delete_generation` - Delete the table when the generation exceeds this value.
+    /// * `min_id_count` - Minimum number of ids a table should have to be evaluated.
+    /// * `time_budget_seconds` - Maximum time the operation is allowed to spend, or 0 for no limit.
+    ///
+    /// # Returns
+    ///
+    /// The number of deleted tables.
+    ///
     /// # See also
     ///
     /// * C API: `ecs_delete_empty_tables`
     #[doc(alias = "ecs_delete_empty_tables")]
-    #[inline(always)] //min_id_count: i32, time_budget_seconds: f64) -> i32
+    #[inline(always)]
     pub fn delete_empty_tables(
         &self,
         id: impl Into<Id>,
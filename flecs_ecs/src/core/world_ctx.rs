@@ -1,11 +1,35 @@
 use super::{FlecsArray, FlecsIdMap, World};
 use crate::sys;
 
+/// Callback set by [`World::set_system_profiler`].
+#[cfg(feature = "flecs_pipeline")]
+pub(crate) type SystemProfiler = std::sync::Arc<dyn Fn(&str, f32) + Send + Sync>;
+
+/// A boxed [`World::run_post_frame`] callback that hasn't run yet.
+pub(crate) struct PendingPostFrame {
+    pub(crate) ctx: *mut std::ffi::c_void,
+    pub(crate) free: unsafe fn(*mut std::ffi::c_void),
+}
+
 pub(crate) struct WorldCtx {
     query_ref_count: i32,
     pub(crate) components: FlecsIdMap,
     pub(crate) components_array: FlecsArray,
     pub(crate) is_panicking: bool,
+    /// Whether the world context was set by [`World::set_ctx`] and holds a `Box<dyn Any>`.
+    pub(crate) has_boxed_ctx: bool,
+    /// Number of active [`World::with_ctx`] callbacks, during which the context can't be replaced.
+    pub(crate) ctx_borrows: std::sync::atomic::AtomicUsize,
+    /// The 0 terminated path set by [`World::set_lookup_path`], which flecs doesn't copy.
+    pub(crate) lookup_path: Vec<u64>,
+    /// Callbacks passed to [`World::run_post_frame`] that still have to run, which are
+    /// freed when the world is destroyed before the frame ends.
+    pub(crate) pending_post_frame: std::sync::Mutex<Vec<PendingPostFrame>>,
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) system_profiler: Option<SystemProfiler>,
+    /// Last frame and total time spent per system at the last report of the system profiler.
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) system_time_spent: std::collections::HashMap<u64, (i64, f32)>,
 }
 
 impl WorldCtx {
@@ -15,6 +39,14 @@ impl WorldCtx {
             components: Default::default(),
             components_array: vec![0; 500],
             is_panicking: false,
+            has_boxed_ctx: false,
+            ctx_borrows: Default::default(),
+            lookup_path: Vec::new(),
+            pending_post_frame: Default::default(),
+            #[cfg(feature = "flecs_pipeline")]
+            system_profiler: None,
+            #[cfg(feature = "flecs_pipeline")]
+            system_time_spent: Default::default(),
         }
     }
 
@@ -53,6 +85,23 @@ impl WorldCtx {
     pub(crate) fn set_is_panicking_true(&mut self) {
         self.is_panicking = true;
     }
+
+    /// Fini action that frees the [`World::run_post_frame`] callbacks that never ran.
+    pub(crate) unsafe extern "C" fn free_pending_post_frame(
+        _world: *mut sys::ecs_world_t,
+        ctx: *mut std::ffi::c_void,
+    ) {
+        let ctx = &*(ctx as *const WorldCtx);
+        let pending = std::mem::take(
+            &mut *ctx
+                .pending_post_frame
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+        );
+        for action in pending {
+            (action.free)(action.ctx);
+        }
+    }
 }
 
 impl World {
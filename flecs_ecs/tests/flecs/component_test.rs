@@ -87,3 +87,764 @@ fn temp_test_hook() {
         assert_eq!(unsafe { COUNT_SET_POS }, 3);
     }
 }
+
+#[test]
+fn component_index_concurrent_first_use() {
+    #[derive(Component)]
+    struct ConcurrentPosition {
+        x: f32,
+        y: f32,
+    }
+
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                let world = World::new();
+                barrier.wait();
+                let id = world.component_id::<ConcurrentPosition>();
+                assert_eq!(world.component_id::<ConcurrentPosition>(), id);
+                ConcurrentPosition::index()
+            })
+        })
+        .collect();
+
+    let indices: Vec<u32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert!(indices.iter().all(|&index| index == indices[0]));
+}
+
+#[test]
+fn component_registered_does_not_register() {
+    #[derive(Component)]
+    struct Unregistered {
+        value: i32,
+    }
+
+    let world = World::new();
+
+    assert!(!world.component_registered::<Unregistered>());
+    assert!(!world.component_registered::<Unregistered>());
+
+    world.component::<Unregistered>();
+
+    assert!(world.component_registered::<Unregistered>());
+}
+
+#[test]
+fn component_trait_exclusive_replaces_target() {
+    let world = World::new();
+
+    world.component::<Likes>().exclusive();
+    assert!(world.component::<Likes>().has::<flecs::Exclusive>());
+
+    let e = world.entity().add::<(Likes, Apples)>();
+    assert!(e.has::<(Likes, Apples)>());
+
+    e.add::<(Likes, Pears)>();
+    assert!(!e.has::<(Likes, Apples)>());
+    assert!(e.has::<(Likes, Pears)>());
+    assert_eq!(e.target_id_count(world.component::<Likes>()), Some(1));
+}
+
+#[test]
+fn component_trait_symmetric() {
+    let world = World::new();
+
+    world.component::<Likes>().symmetric();
+
+    let a = world.entity();
+    let b = world.entity();
+    a.add_first::<Likes>(b);
+
+    assert!(a.has_first::<Likes>(b));
+    assert!(b.has_first::<Likes>(a));
+}
+
+#[test]
+fn component_trait_transitive() {
+    #[derive(Component)]
+    struct LocatedIn;
+
+    let world = World::new();
+
+    world.component::<LocatedIn>().transitive();
+    assert!(world.component::<LocatedIn>().has::<flecs::Transitive>());
+
+    let europe = world.entity_named("Europe");
+    let france = world.entity_named("France").add_first::<LocatedIn>(europe);
+    let paris = world.entity_named("Paris").add_first::<LocatedIn>(france);
+    world.entity_named("Tokyo");
+
+    let q = world.query::<()>().with_first::<LocatedIn>(europe).build();
+
+    let mut found = Vec::new();
+    q.each_entity(|e, _| found.push(e.id()));
+    found.sort();
+
+    assert_eq!(found, vec![france.id(), paris.id()]);
+}
+
+#[test]
+fn component_trait_with() {
+    let world = World::new();
+
+    world.component::<Velocity>().with::<Position>();
+
+    let e = world.entity().add::<Velocity>();
+    assert!(e.has::<Velocity>());
+    assert!(e.has::<Position>());
+
+    let e2 = world.entity().set(Velocity { x: 1, y: 2 });
+    assert!(e2.has::<Position>());
+
+    e.remove::<Velocity>();
+    assert!(!e.has::<Velocity>());
+    assert!(e.has::<Position>());
+}
+
+#[test]
+fn component_add_custom_trait() {
+    #[derive(Component)]
+    struct Serializable;
+
+    let world = World::new();
+
+    world.component::<Position>().add_trait::<Serializable>();
+    world.component::<Mass>().add_trait::<Serializable>();
+    world.component::<Velocity>();
+
+    assert!(world.component::<Position>().has::<Serializable>());
+    assert!(!world.component::<Velocity>().has::<Serializable>());
+
+    let mut found = vec![];
+    world
+        .query::<(&Serializable,)>()
+        .build()
+        .each_entity(|e, _| found.push(e.id()));
+
+    assert_eq!(found.len(), 2);
+    assert!(found.contains(&world.component_id::<Position>()));
+    assert!(found.contains(&world.component_id::<Mass>()));
+}
+
+#[test]
+fn component_on_instantiate_policies() {
+    let world = World::new();
+
+    world.component::<Position>().on_instantiate_override();
+    world.component::<Velocity>().on_instantiate_inherit();
+    world.component::<Mass>().on_instantiate_dont_inherit();
+
+    assert!(world
+        .component::<Mass>()
+        .has_id((*flecs::OnInstantiate, *flecs::DontInherit)));
+
+    let prefab = world
+        .prefab()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 })
+        .set(Mass { value: 5 });
+
+    let inst = world.entity().is_a_id(prefab);
+
+    assert!(inst.owns::<Position>());
+    inst.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 2)));
+
+    assert!(inst.has::<Velocity>());
+    assert!(!inst.owns::<Velocity>());
+
+    assert!(!inst.has::<Mass>());
+    assert!(!inst.try_get::<&Mass>(|_| {}));
+}
+
+#[test]
+fn component_doc_brief_and_color() {
+    use flecs_ecs::addons::doc::Doc;
+
+    let world = World::new();
+
+    let pos = world.component::<Position>();
+    assert_eq!(pos.get_doc_brief(), None);
+    assert_eq!(pos.get_doc_color(), None);
+
+    pos.set_doc_brief("A position in 2D space")
+        .set_doc_color("#ff0000");
+
+    assert_eq!(pos.get_doc_brief(), Some("A position in 2D space"));
+    assert_eq!(pos.get_doc_color(), Some("#ff0000"));
+    assert_eq!(
+        world.get_doc_brief::<Position>(),
+        Some("A position in 2D space")
+    );
+    assert_eq!(world.get_doc_color_id(pos), Some("#ff0000"));
+
+    // falls back to the entity name when no doc name is set
+    assert_eq!(pos.get_doc_name(), Some("Position"));
+    pos.set_doc_name("Pos");
+    assert_eq!(world.get_doc_name::<Position>(), Some("Pos"));
+}
+
+#[test]
+fn component_member_unit_json() {
+    use flecs_ecs::addons::units::Units;
+    use flecs_ecs::sys;
+
+    #[derive(Component)]
+    struct Movement {
+        speed: f32,
+    }
+
+    let world = World::new();
+    world.import::<Units>();
+
+    let movement = world.component::<Movement>();
+    movement.member_unit::<f32>("speed", flecs::units::MetersPerSecond);
+
+    let speed = movement.lookup("speed");
+    assert!(speed.has::<flecs::meta::Member>());
+
+    let json = unsafe {
+        let ptr = sys::ecs_type_info_to_json(world.ptr_mut(), *movement.id());
+        let json = std::ffi::CStr::from_ptr(ptr).to_str().unwrap().to_string();
+        sys::ecs_os_api.free_.unwrap()(ptr as *mut _);
+        json
+    };
+
+    assert!(json.contains("\"speed\""));
+    assert!(json.contains("\"unit\":\"flecs.units.Speed.MetersPerSecond\""));
+    assert!(json.contains("\"symbol\":\"m/s\""));
+}
+
+#[test]
+fn component_meta_derive_json() {
+    use flecs_ecs::sys;
+
+    #[derive(Component, Meta)]
+    struct Stats {
+        alive: bool,
+        health: f32,
+        kills: u64,
+        level: i16,
+    }
+
+    let world = World::new();
+
+    let stats = Stats {
+        alive: true,
+        health: 12.5,
+        kills: 3,
+        level: -2,
+    };
+
+    let json = unsafe {
+        let id = world.component::<Stats>().id();
+        let ptr = sys::ecs_ptr_to_json(world.ptr_mut(), *id, &stats as *const _ as *const _);
+        assert!(!ptr.is_null());
+        let json = std::ffi::CStr::from_ptr(ptr).to_str().unwrap().to_string();
+        sys::ecs_os_api.free_.unwrap()(ptr as *mut _);
+        json
+    };
+
+    // members are ordered by their offset in the Rust layout
+    assert!(json.contains("\"alive\":true"));
+    assert!(json.contains("\"health\":12.5"));
+    assert!(json.contains("\"kills\":3"));
+    assert!(json.contains("\"level\":-2"));
+    assert_eq!(json.matches(':').count(), 4);
+}
+
+#[test]
+fn component_is_a_inheritance() {
+    #[derive(Component)]
+    struct Shape;
+
+    #[derive(Component)]
+    struct Sphere;
+
+    let world = World::new();
+
+    world.component::<Sphere>().is_a::<Shape>();
+
+    let e = world.entity().add::<Sphere>();
+    world.entity().add::<Position>();
+
+    let query = world.new_query::<&Shape>();
+    let mut count = 0;
+    query.each_entity(|entity, _| {
+        assert_eq!(entity, e);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn component_id_size() {
+    let world = World::new();
+
+    assert_eq!(
+        world.id_from::<Position>().size(),
+        std::mem::size_of::<Position>()
+    );
+    assert_eq!(world.id_from::<Mass>().size(), std::mem::size_of::<Mass>());
+    assert_eq!(world.id_from::<TagA>().size(), 0);
+
+    // pairs take the size of the component element
+    assert_eq!(
+        world.id_from::<(Position, TagA)>().size(),
+        std::mem::size_of::<Position>()
+    );
+    assert_eq!(world.id_from::<(Likes, Apples)>().size(), 0);
+}
+
+#[test]
+fn component_hooks_invocation_count() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let world = World::new();
+
+    let added = Rc::new(Cell::new(0));
+    let set = Rc::new(Cell::new(0));
+    let removed = Rc::new(Cell::new(0));
+
+    {
+        let (added, set, removed) = (added.clone(), set.clone(), removed.clone());
+        world
+            .component::<Position>()
+            .on_add(move |_e, _p: &mut Position| added.set(added.get() + 1))
+            .on_set(move |_e, _p: &mut Position| set.set(set.get() + 1))
+            .on_remove(move |_e, _p: &mut Position| removed.set(removed.get() + 1));
+    }
+
+    let e = world.entity().set(Position { x: 1, y: 2 });
+    assert_eq!(added.get(), 1);
+    assert_eq!(set.get(), 1);
+
+    e.set(Position { x: 3, y: 4 });
+    e.set(Position { x: 5, y: 6 });
+    assert_eq!(added.get(), 1);
+    assert_eq!(set.get(), 3);
+
+    // adding without a value only invokes on_add
+    let e2 = world.entity().add::<Position>();
+    assert_eq!(added.get(), 2);
+    assert_eq!(set.get(), 3);
+
+    e.remove::<Position>();
+    e2.destruct();
+    assert_eq!(removed.get(), 2);
+}
+
+#[test]
+#[cfg(feature = "flecs_meta")]
+fn component_constant() {
+    #[derive(Component)]
+    #[repr(C)]
+    struct Color(i32);
+
+    let world = World::new();
+
+    let color = world.component::<Color>();
+    color.constant("Red", 1).constant("Blue", 4);
+
+    assert!(color.has::<flecs::meta::Enum>());
+
+    let blue = color.lookup("Blue");
+    assert_eq!(blue.parent().unwrap(), color.id());
+
+    let value = blue
+        .get_raw((flecs::meta::Constant::ID, flecs::meta::I32::ID))
+        .unwrap();
+    assert_eq!(i32::from_ne_bytes(value.try_into().unwrap()), 4);
+
+    let e = world.entity().add_id((color, blue));
+    assert_eq!(e.target_id(color, 0).unwrap(), blue);
+}
+
+#[test]
+#[cfg(feature = "flecs_json")]
+fn component_bitmask_json_round_trip() {
+    use flecs_ecs::sys;
+    use std::ffi::CStr;
+
+    #[derive(Component)]
+    #[repr(C)]
+    struct Toppings(u32);
+
+    const BACON: u32 = 1 << 0;
+    const LETTUCE: u32 = 1 << 1;
+    const TOMATO: u32 = 1 << 2;
+
+    let world = World::new();
+
+    let toppings = world.component::<Toppings>();
+    toppings
+        .bit("Bacon", BACON)
+        .bit("Lettuce", LETTUCE)
+        .bit("Tomato", TOMATO);
+
+    assert!(toppings.has::<flecs::meta::Bitmask>());
+    assert!(!toppings.has::<flecs::meta::Enum>());
+
+    let json = unsafe {
+        let value = Toppings(BACON | TOMATO);
+        let json = sys::ecs_ptr_to_json(
+            world.ptr_mut(),
+            *toppings.id(),
+            &value as *const Toppings as *const _,
+        );
+        assert!(!json.is_null());
+        let result = CStr::from_ptr(json).to_str().unwrap().to_string();
+        sys::ecs_os_api.free_.expect("os api is missing free")(json as *mut _);
+        result
+    };
+    // flags are serialized in the order of the constant map, not in order of value
+    let mut flags: Vec<&str> = json.trim_matches('"').split('|').collect();
+    flags.sort();
+    assert_eq!(flags, ["Bacon", "Tomato"]);
+
+    let mut value = Toppings(0);
+    let json = std::ffi::CString::new(json).unwrap();
+    let rest = unsafe {
+        sys::ecs_ptr_from_json(
+            world.ptr_mut(),
+            *toppings.id(),
+            &mut value as *mut Toppings as *mut _,
+            json.as_ptr(),
+            std::ptr::null(),
+        )
+    };
+    assert!(!rest.is_null());
+    assert_eq!(value.0, BACON | TOMATO);
+
+    let rest = unsafe {
+        sys::ecs_ptr_from_json(
+            world.ptr_mut(),
+            *toppings.id(),
+            &mut value as *mut Toppings as *mut _,
+            c"\"Lettuce\"".as_ptr(),
+            std::ptr::null(),
+        )
+    };
+    assert!(!rest.is_null());
+    assert_eq!(value.0, LETTUCE);
+}
+
+#[test]
+#[cfg(feature = "flecs_meta")]
+fn component_typed_handle_member_and_hook() {
+    #[derive(Component)]
+    struct Health {
+        current: i32,
+        max: i32,
+    }
+
+    #[derive(Component)]
+    struct Stat;
+
+    let world = World::new();
+
+    world.component::<Stat>();
+    world
+        .component::<Health>()
+        .on_set(|_e, h| h.current = h.current.min(h.max))
+        .is_a::<Stat>()
+        .member::<i32>("current");
+
+    let health = world.component::<Health>();
+    health.member::<i32>("max");
+
+    assert!(health.has_id((*flecs::IsA, world.component_id::<Stat>())));
+    assert!(health.lookup("current").has::<flecs::meta::Member>());
+    assert!(health.lookup("max").has::<flecs::meta::Member>());
+    assert!(health.has::<flecs::meta::StructT>());
+
+    let e = world.entity().set(Health {
+        current: 150,
+        max: 100,
+    });
+    e.get::<&Health>(|h| assert_eq!(h.current, 100));
+}
+
+#[test]
+fn component_copy_hooks_from() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Component)]
+    struct Tracked {
+        text: String,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Component)]
+    #[repr(transparent)]
+    struct Wrapper(Tracked);
+
+    let world = World::new();
+
+    let tracked = world.component::<Tracked>();
+    let wrapper_hooks_before = world.component::<Wrapper>().get_hooks();
+    unsafe { world.component::<Wrapper>().copy_hooks_from::<Tracked>() };
+
+    let dtor = |hooks: flecs_ecs::sys::ecs_type_hooks_t| hooks.dtor.map(|f| f as usize);
+    let hooks = world.component::<Wrapper>().get_hooks();
+    assert_eq!(dtor(hooks), dtor(tracked.get_hooks()));
+    assert_ne!(dtor(hooks), dtor(wrapper_hooks_before));
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let e = world.entity().set(Wrapper(Tracked {
+        text: "hello".to_string(),
+        drops: drops.clone(),
+    }));
+
+    // moving the value to another table must not drop it
+    e.add::<Position>();
+    e.get::<&Wrapper>(|w| assert_eq!(w.0.text, "hello"));
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+    e.destruct();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[should_panic(expected = "must have the same size and alignment")]
+fn component_copy_hooks_from_size_mismatch() {
+    #[derive(Component)]
+    struct Small(u8);
+
+    let world = World::new();
+    unsafe { world.component::<Position>().copy_hooks_from::<Small>() };
+}
+
+#[test]
+#[cfg(feature = "flecs_json")]
+fn component_opaque_json_round_trip() {
+    use flecs_ecs::sys;
+    use std::ffi::{c_char, CStr};
+
+    #[derive(Component)]
+    struct Label(String);
+
+    extern "C" fn serialize(ser: &Serializer, label: &Label) -> i32 {
+        ser.value_str(&label.0)
+    }
+
+    extern "C" fn assign_string(label: &mut Label, value: *const c_char) {
+        label.0 = unsafe { CStr::from_ptr(value) }
+            .to_string_lossy()
+            .into_owned();
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Label>()
+        .opaque::<flecs::meta::String>()
+        .serialize(serialize)
+        .assign_string(assign_string);
+
+    let label_id = world.component_id::<Label>();
+    let to_json = |label: &Label| unsafe {
+        let json = sys::ecs_ptr_to_json(
+            world.ptr_mut(),
+            *label_id,
+            label as *const Label as *const _,
+        );
+        assert!(!json.is_null());
+        let result = CStr::from_ptr(json).to_str().unwrap().to_string();
+        sys::ecs_os_api.free_.expect("os api is missing free")(json as *mut _);
+        result
+    };
+
+    let json = to_json(&Label("hello \"world\"".to_string()));
+    assert_eq!(json, r#""hello \"world\"""#);
+
+    let mut label = Label(String::new());
+    let rest = unsafe {
+        sys::ecs_ptr_from_json(
+            world.ptr_mut(),
+            *label_id,
+            &mut label as *mut Label as *mut _,
+            c"\"from json\"".as_ptr(),
+            std::ptr::null(),
+        )
+    };
+    assert!(!rest.is_null());
+    assert_eq!(label.0, "from json");
+
+    label.0 = "round trip".to_string();
+    let mut copy = Label(String::new());
+    let json = std::ffi::CString::new(to_json(&label)).unwrap();
+    unsafe {
+        sys::ecs_ptr_from_json(
+            world.ptr_mut(),
+            *label_id,
+            &mut copy as *mut Label as *mut _,
+            json.as_ptr(),
+            std::ptr::null(),
+        );
+    }
+    assert_eq!(copy.0, "round trip");
+}
+
+#[test]
+#[cfg(feature = "flecs_json")]
+fn component_member_range() {
+    use flecs_ecs::sys;
+    use std::ffi::CStr;
+
+    #[derive(Component)]
+    #[repr(C)]
+    struct Health {
+        hp: f32,
+        armor: f32,
+    }
+
+    let world = World::new();
+
+    let health = world.component::<Health>();
+    health
+        .member_range::<f32>("hp", 0.0, 100.0)
+        .member::<f32>("armor");
+
+    assert!(health.lookup("hp").has::<flecs::meta::MemberRanges>());
+    assert!(!health.lookup("armor").has::<flecs::meta::MemberRanges>());
+
+    let members = unsafe {
+        let st = sys::ecs_get_id(world.ptr_mut(), *health.id(), flecs::meta::StructT::ID)
+            as *const sys::EcsStruct;
+        assert!(!st.is_null());
+        std::slice::from_raw_parts(
+            (*st).members.array as *const sys::ecs_member_t,
+            (*st).members.count as usize,
+        )
+    };
+    assert_eq!(members.len(), 2);
+    assert_eq!((members[0].range.min, members[0].range.max), (0.0, 100.0));
+    assert_eq!((members[1].range.min, members[1].range.max), (0.0, 0.0));
+
+    let type_info = unsafe {
+        let json = sys::ecs_type_info_to_json(world.ptr_mut(), *health.id());
+        let result = CStr::from_ptr(json).to_str().unwrap().to_string();
+        sys::ecs_os_api.free_.expect("os api is missing free")(json as *mut _);
+        result
+    };
+    assert_eq!(
+        type_info,
+        r#"{"hp":["float", {"range":[0, 100]}], "armor":["float"]}"#
+    );
+
+    // the range is informational: the deserializer does not reject values outside of it
+    let mut value = Health {
+        hp: 0.0,
+        armor: 0.0,
+    };
+    let rest = unsafe {
+        sys::ecs_ptr_from_json(
+            world.ptr_mut(),
+            *health.id(),
+            &mut value as *mut Health as *mut _,
+            c"{\"hp\": 150, \"armor\": 5}".as_ptr(),
+            std::ptr::null(),
+        )
+    };
+    assert!(!rest.is_null());
+    assert_eq!((value.hp, value.armor), (150.0, 5.0));
+}
+
+#[test]
+fn component_set_symbol_same_type_name() {
+    mod crate_a {
+        use flecs_ecs::prelude::*;
+
+        #[derive(Component)]
+        pub struct Position {
+            pub x: i64,
+        }
+    }
+
+    mod crate_b {
+        use flecs_ecs::prelude::*;
+
+        #[derive(Component)]
+        pub struct Position {
+            pub x: i32,
+        }
+    }
+
+    let world = World::new();
+
+    world
+        .component::<crate_a::Position>()
+        .set_symbol("crate_a.Position");
+    world
+        .component::<crate_b::Position>()
+        .set_symbol("crate_b.Position");
+
+    let a = world.component_id::<crate_a::Position>();
+    let b = world.component_id::<crate_b::Position>();
+    assert_ne!(a, b);
+
+    assert_eq!(world.lookup_symbol("crate_a.Position"), a);
+    assert_eq!(world.lookup_symbol("crate_b.Position"), b);
+    assert_eq!(world.entity_from_id(a).symbol(), "crate_a.Position");
+    assert_eq!(world.entity_from_id(b).symbol(), "crate_b.Position");
+
+    let e = world
+        .entity()
+        .set(crate_a::Position { x: 1 })
+        .set(crate_b::Position { x: 2 });
+    e.get::<(&crate_a::Position, &crate_b::Position)>(|(pa, pb)| {
+        assert_eq!(pa.x, 1);
+        assert_eq!(pb.x, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "must be separated by `.`")]
+fn component_set_symbol_unqualified() {
+    let world = World::new();
+
+    // registration matches components by the short type name, so this would make
+    // any other `Position` type resolve to the id of this component
+    world.component::<Position>().set_symbol("Position");
+}
+
+#[test]
+#[should_panic(expected = "is already used")]
+fn component_set_symbol_already_used() {
+    let world = World::new();
+
+    world.component::<Position>().set_symbol("game.Position");
+    world.component::<Velocity>().set_symbol("game.Position");
+}
+
+#[test]
+#[should_panic(expected = "must be separated by `.`")]
+fn component_set_symbol_type_path() {
+    mod other {
+        use flecs_ecs::prelude::*;
+
+        #[derive(Component)]
+        pub struct Position {
+            pub x: f32,
+        }
+    }
+
+    let world = World::new();
+
+    // the path of a type that isn't registered yet would clash once it is
+    world
+        .component::<Position>()
+        .set_symbol(std::any::type_name::<other::Position>());
+}
@@ -323,6 +323,43 @@ fn entity_remove_instanceof() {
     assert!(!entity.has_id((flecs::IsA::ID, base)));
 }
 
+#[test]
+fn entity_remove_pair_wildcard() {
+    let world = World::new();
+
+    let entity = world
+        .entity()
+        .add::<(Likes, Apples)>()
+        .add::<(Likes, Pears)>()
+        .add::<(Eats, Apples)>();
+
+    assert!(entity.has::<(Likes, Apples)>());
+    assert!(entity.has::<(Likes, Pears)>());
+
+    entity.remove_pair_wildcard::<Likes>();
+
+    assert!(!entity.has::<(Likes, Apples)>());
+    assert!(!entity.has::<(Likes, Pears)>());
+    assert!(entity.has::<(Eats, Apples)>());
+}
+
+#[test]
+fn entity_has_pair_wildcard() {
+    let world = World::new();
+
+    let entity = world.entity().add::<(Eats, Apples)>();
+    assert!(!entity.has_pair_wildcard::<Likes>());
+
+    entity.add::<(Likes, Apples)>();
+    assert!(entity.has_pair_wildcard::<Likes>());
+    assert!(entity.has::<(Likes, Apples)>());
+    assert!(!entity.has::<(Likes, Pears)>());
+
+    entity.remove_pair_wildcard::<Likes>();
+    assert!(!entity.has_pair_wildcard::<Likes>());
+    assert!(entity.has_pair_wildcard::<Eats>());
+}
+
 #[test]
 fn entity_get_generic() {
     let world = World::new();
@@ -864,6 +901,24 @@ fn entity_get_target() {
     assert!(child.target_id(rel, 3).is_none());
 }
 
+#[test]
+fn entity_get_target_typed() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+
+    let e = world
+        .entity()
+        .add_first::<Likes>(apples)
+        .add_first::<Likes>(pears);
+
+    assert_eq!(e.target::<Likes>(0).unwrap(), apples);
+    assert_eq!(e.target::<Likes>(1).unwrap(), pears);
+    assert!(e.target::<Likes>(2).is_none());
+    assert!(e.target::<Eats>(0).is_none());
+}
+
 #[test]
 fn entity_get_parent() {
     let world = World::new();
@@ -873,6 +928,7 @@ fn entity_get_parent() {
 
     assert_eq!(child.target_id(flecs::ChildOf::ID, 0).unwrap(), parent);
     assert_eq!(child.parent().unwrap(), parent);
+    assert!(parent.parent().is_none());
 }
 
 /// # See also
@@ -1364,6 +1420,59 @@ fn entity_set_auto_override_pair_w_rel_tag() {
     });
 }
 
+#[test]
+fn entity_override_pair_data_from_prefab() {
+    #[derive(Component)]
+    struct Slot;
+
+    #[derive(Component)]
+    struct Weapon {
+        damage: i32,
+    }
+
+    let world = World::new();
+
+    // the relationship determines how the pair is instantiated
+    world
+        .component::<Slot>()
+        .add_id((*flecs::OnInstantiate, *flecs::Inherit));
+
+    let prefab = world
+        .prefab()
+        .set_pair::<Slot, Weapon>(Weapon { damage: 10 });
+
+    let overridden = world.entity().is_a_id(prefab);
+    let shared = world.entity().is_a_id(prefab);
+
+    assert!(overridden.has::<(Slot, Weapon)>());
+    assert!(!overridden.owns::<(Slot, Weapon)>());
+
+    overridden.override_type::<(Slot, Weapon)>();
+
+    assert!(overridden.owns::<(Slot, Weapon)>());
+    assert!(!shared.owns::<(Slot, Weapon)>());
+
+    overridden.get::<&mut (Slot, Weapon)>(|weapon| {
+        assert_eq!(weapon.damage, 10);
+        weapon.damage = 20;
+    });
+
+    overridden.get::<&(Slot, Weapon)>(|weapon| assert_eq!(weapon.damage, 20));
+    shared.get::<&(Slot, Weapon)>(|weapon| assert_eq!(weapon.damage, 10));
+    prefab.get::<&(Slot, Weapon)>(|weapon| assert_eq!(weapon.damage, 10));
+
+    // auto-overriding the pair on the prefab gives every instance its own copy
+    let auto_prefab = world
+        .prefab()
+        .set_pair_override::<Slot, Weapon>(Weapon { damage: 30 });
+
+    let instance = world.entity().is_a_id(auto_prefab);
+
+    assert!(instance.owns::<(Slot, Weapon)>());
+    instance.get::<&mut (Slot, Weapon)>(|weapon| weapon.damage = 40);
+    auto_prefab.get::<&(Slot, Weapon)>(|weapon| assert_eq!(weapon.damage, 30));
+}
+
 #[test]
 fn entity_name() {
     let world = World::new();
@@ -1388,6 +1497,31 @@ fn entity_name_empty() {
     assert_eq!(entity.get_name_cstr(), None);
 }
 
+#[test]
+fn entity_display_name() {
+    let world = World::new();
+
+    let named = world.entity_named("parent::Foo");
+    assert_eq!(named.display_name(), "Foo");
+
+    let unnamed = world.entity();
+    let expected = format!("#{}", *unnamed.id() as u32);
+    assert_eq!(unnamed.display_name(), expected);
+
+    // matches the flecs path of an unnamed child
+    let child = world.entity().child_of_id(named);
+    assert_eq!(
+        child.path().unwrap(),
+        format!("::parent::Foo::{}", child.display_name())
+    );
+
+    // the generation is not part of the name
+    unnamed.destruct();
+    let recycled = world.entity();
+    assert_ne!(recycled.id(), unnamed.id());
+    assert_eq!(recycled.display_name(), expected);
+}
+
 #[test]
 fn entity_path() {
     let world = World::new();
@@ -2937,6 +3071,27 @@ fn entity_id_from_world() {
     assert!(id_2.is_wildcard());
 }
 
+#[test]
+fn entity_id_view_conversion() {
+    let world = World::new();
+
+    let e = world.entity();
+    let tgt = world.entity();
+
+    let id: IdView = e.into();
+    assert_eq!(id, e);
+    assert!(id.is_entity());
+    assert_eq!(id.get_entity_view().unwrap(), e);
+    assert_eq!(id.entity_view(), e);
+
+    let pair = world.id_from_id((e, tgt));
+    assert!(pair.is_pair());
+    assert!(pair.get_entity_view().is_none());
+
+    let flagged = world.id_from_id(flecs::id_flags::AutoOverride::ID | e.id());
+    assert!(flagged.get_entity_view().is_none());
+}
+
 #[test]
 fn entity_id_pair_from_world() {
     let world = World::new();
@@ -3748,6 +3903,22 @@ fn entity_set_alias() {
     assert_eq!(e, world.lookup_recursive("parent_child"));
 }
 
+#[test]
+fn entity_set_alias_lookup() {
+    let world = World::new();
+
+    let e = world.entity_named("Robert").set_alias("Bob");
+    assert_eq!(e.name(), "Robert");
+
+    assert_eq!(e, world.lookup("Robert"));
+    assert_eq!(e, world.lookup("Bob"));
+    assert_eq!(world.try_lookup("Bob").map(|b| b.id()), Some(e.id()));
+
+    let child = world.entity_named("parent::child").set_alias("kid");
+    assert_eq!(child, world.lookup("parent::child"));
+    assert_eq!(child, world.lookup("kid"));
+}
+
 #[test]
 fn entity_insert_w_observer() {
     let world = World::new();
@@ -3836,3 +4007,403 @@ fn entity_id_reuse() {
     );
     assert_eq!(b.archetype().to_string(), first_archetype);
 }
+
+#[test]
+fn entity_set_many() {
+    let world = World::new();
+
+    // if the components are set in a single table move, the entity already
+    // has all of them when the OnAdd observer for the first one runs
+    world
+        .observer::<flecs::OnAdd, &Position>()
+        .each_entity(|e, _| {
+            assert!(e.has::<Velocity>());
+            assert!(e.has::<Mass>());
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+    world.set(Count(0));
+
+    let e = world.entity().set_many((
+        Position { x: 10, y: 20 },
+        Velocity { x: 1, y: 2 },
+        Mass { value: 50 },
+    ));
+
+    e.get::<(&Position, &Velocity, &Mass)>(|(pos, vel, mass)| {
+        assert_eq!(pos.x, 10);
+        assert_eq!(pos.y, 20);
+        assert_eq!(vel.x, 1);
+        assert_eq!(vel.y, 2);
+        assert_eq!(mass.value, 50);
+    });
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+}
+
+#[test]
+fn entity_set_many_generic() {
+    // `SetTuple` is public, so helpers can forward any tuple to `set_many`
+    fn spawn<T: SetTuple>(world: &World, components: T) -> EntityView<'_> {
+        world.entity().set_many(components)
+    }
+
+    let world = World::new();
+
+    let e = spawn(&world, (Position { x: 1, y: 2 }, Velocity { x: 3, y: 4 }));
+    assert!(e.has::<Position>());
+    assert!(e.has::<Velocity>());
+}
+
+#[test]
+fn entity_insert_in_place() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static DEFAULTS: AtomicU32 = AtomicU32::new(0);
+    static CLONES: AtomicU32 = AtomicU32::new(0);
+
+    #[derive(Component)]
+    struct Large {
+        data: [u64; 512],
+    }
+
+    impl Default for Large {
+        fn default() -> Self {
+            DEFAULTS.fetch_add(1, Ordering::SeqCst);
+            Large { data: [0; 512] }
+        }
+    }
+
+    impl Clone for Large {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Large { data: self.data }
+        }
+    }
+
+    let world = World::new();
+
+    let e = world.entity().insert(|l: &mut Large| {
+        l.data[0] = 1;
+        l.data[511] = 2;
+    });
+
+    e.get::<&Large>(|l| {
+        assert_eq!(l.data[0], 1);
+        assert_eq!(l.data[511], 2);
+    });
+    assert_eq!(DEFAULTS.load(Ordering::SeqCst), 1);
+
+    // initializes over the existing value
+    e.insert(|l: &mut Large| {
+        assert_eq!(l.data[0], 1);
+        l.data[0] = 3;
+    });
+
+    e.get::<&Large>(|l| {
+        assert_eq!(l.data[0], 3);
+        assert_eq!(l.data[511], 2);
+    });
+    assert_eq!(DEFAULTS.load(Ordering::SeqCst), 1);
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn entity_modified_after_raw_write() {
+    let world = World::new();
+    world.set(Count(0));
+
+    let e = world.entity().set(Position { x: 10, y: 20 });
+
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|entity, _| {
+            entity.world().get::<&mut Count>(|count| {
+                count.0 += 1;
+            });
+        });
+
+    // change detection requires a cached query
+    let q = world.query::<&Position>().set_cached().build();
+    assert!(q.is_changed());
+    q.each(|_| {});
+    assert!(!q.is_changed());
+
+    let pos = e.get_untyped_mut(world.component_id::<Position>()) as *mut Position;
+    unsafe { (*pos).x = 30 };
+
+    // writing through the pointer alone does not signal a change
+    assert!(!q.is_changed());
+    world.get::<&Count>(|count| assert_eq!(count.0, 0));
+
+    e.modified::<Position>();
+
+    assert!(q.is_changed());
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+    e.get::<&Position>(|pos| assert_eq!(pos.x, 30));
+}
+
+#[test]
+fn entity_chain_mutators() {
+    let world = World::new();
+    let bob = world.entity();
+
+    let build = || {
+        world
+            .entity()
+            .add::<TagA>()
+            .set(Position { x: 10, y: 20 })
+            .set(Velocity { x: 1, y: 2 })
+            .remove::<Velocity>()
+            .add::<(Likes, Apples)>()
+            .add_first::<Eats>(bob)
+            .modified::<Position>()
+            .remove::<TagA>()
+            .disable_self()
+    };
+
+    let e = build();
+    let deferred = world.defer(build);
+
+    for e in [e, deferred] {
+        assert!(e.has::<Position>());
+        assert!(!e.has::<Velocity>());
+        assert!(!e.has::<TagA>());
+        assert!(e.has::<(Likes, Apples)>());
+        assert!(e.has_first::<Eats>(bob));
+        assert!(!e.is_enabled_self());
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 20)));
+    }
+}
+
+#[test]
+fn entity_copy_raw_bytes() {
+    let world = World::new();
+
+    let src = world.entity().set(Position { x: 10, y: 20 }).add::<TagA>();
+    let dst = world.entity();
+
+    let pos = world.component::<Position>();
+    let bytes = src.get_raw(pos).unwrap();
+    assert_eq!(bytes.len(), std::mem::size_of::<Position>());
+    assert!(src.get_raw(world.component::<TagA>()).is_none());
+    assert!(dst.get_raw(pos).is_none());
+
+    dst.set_raw(pos, &bytes);
+
+    dst.get::<&Position>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+}
+
+#[test]
+fn entity_copy_raw_bytes_same_table() {
+    let world = World::new();
+    let pos = world.component::<Position>();
+
+    let src = world.entity().set(Position { x: 10, y: 20 });
+    let bytes = src.get_raw(pos).unwrap();
+
+    // fill the table so that setting the component on new entities grows its columns
+    let copies: Vec<EntityView> = (0..64)
+        .map(|_| world.entity().set_raw(pos, &bytes))
+        .collect();
+
+    for e in copies {
+        assert_eq!(e.archetype().to_string(), src.archetype().to_string());
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 20)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected 8 bytes, got 4")]
+fn entity_set_raw_size_mismatch() {
+    let world = World::new();
+
+    world
+        .entity()
+        .set_raw(world.component::<Position>(), &[0u8; 4]);
+}
+
+#[test]
+fn entity_set_pair_second_data() {
+    #[derive(Component)]
+    struct Food {
+        calories: u32,
+    }
+
+    let world = World::new();
+
+    let e = world.entity().set_pair::<Eats, Food>(Food { calories: 10 });
+
+    assert!(e.has::<(Eats, Food)>());
+    assert_eq!(
+        world.id_from::<(Eats, Food)>().type_id(),
+        world.component::<Food>().id()
+    );
+    e.get::<&(Eats, Food)>(|food| assert_eq!(food.calories, 10));
+
+    // overwriting through a runtime relationship id
+    let eats = world.component::<Eats>();
+    e.set_second::<Food>(eats, Food { calories: 20 });
+    e.get::<&(Eats, Food)>(|food| assert_eq!(food.calories, 20));
+
+    e.get::<&mut (Eats, Food)>(|food| food.calories += 5);
+    e.get::<&(Eats, Food)>(|food| assert_eq!(food.calories, 25));
+
+    // the pair does not add the target as a component
+    assert!(!e.has::<Food>());
+}
+
+#[test]
+fn entity_has_vs_owns_inherited() {
+    let world = World::new();
+    world.component::<Position>().on_instantiate_inherit();
+
+    let base = world.prefab().set(Position { x: 1, y: 2 });
+    let instance = world.entity().is_a_id(base);
+
+    assert!(instance.has::<Position>());
+    assert!(!instance.owns::<Position>());
+    assert!(instance.has_id(world.component_id::<Position>()));
+    assert!(!instance.owns_id(world.component_id::<Position>()));
+    assert!(base.has::<Position>());
+    assert!(base.owns::<Position>());
+
+    instance.override_type::<Position>();
+
+    assert!(instance.has::<Position>());
+    assert!(instance.owns::<Position>());
+    instance.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 2)));
+}
+
+#[test]
+fn entity_child_of_builder() {
+    let world = World::new();
+
+    let parent = world.entity_named("Parent");
+    let child = world
+        .entity()
+        .child_of_id(parent)
+        .set_name("Child")
+        .set(Position { x: 1, y: 2 });
+
+    assert!(child.has_id((flecs::ChildOf::ID, parent)));
+    assert_eq!(child.parent(), Some(parent));
+    assert_eq!(child.path().unwrap(), "::Parent::Child");
+    assert_eq!(world.lookup("Parent::Child"), child);
+
+    let grandchild = world.entity().child_of_id(child).set_name("Grandchild");
+    assert_eq!(grandchild.parent(), Some(child));
+    assert_eq!(grandchild.path().unwrap(), "::Parent::Child::Grandchild");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "pair relationship must not be 0")]
+fn entity_add_pair_with_zero_id() {
+    let world = World::new();
+
+    let unregistered = Entity::new(0);
+    let tgt = world.entity();
+
+    world.entity().add_id((unregistered, tgt));
+}
+
+#[test]
+fn entity_query_root_scope_pair() {
+    let world = World::new();
+
+    let parent = world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .child_of_id(parent);
+
+    // `(ChildOf, 0)` matches entities in the root scope
+    let q = world
+        .query::<&Position>()
+        .with_id((flecs::ChildOf::ID, Entity::new(0)))
+        .build();
+
+    let mut count = 0;
+    q.each_entity(|e, _| {
+        assert_eq!(e, parent);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+
+    // flecs rewrites `!(ChildOf, *)` to a `(ChildOf, 0)` term
+    let q = world
+        .query::<&Position>()
+        .without_id((flecs::ChildOf::ID, flecs::Wildcard::ID))
+        .build();
+
+    let mut count = 0;
+    q.each_iter(|it, i, _| {
+        assert_eq!(it.entity(i), parent);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn entity_set_ptr_w_size_from_c_memory() {
+    use flecs_ecs::sys;
+
+    let world = World::new();
+    let position = world.component::<Position>();
+
+    let size = std::mem::size_of::<Position>();
+    let e = unsafe {
+        let ptr = sys::ecs_os_api.malloc_.expect("os api is missing malloc")(size as i32)
+            as *mut Position;
+        ptr.write(Position { x: 10, y: 20 });
+
+        let e = world
+            .entity()
+            .set_ptr_w_size(position, size, ptr as *const c_void);
+
+        sys::ecs_os_api.free_.expect("os api is missing free")(ptr as *mut c_void);
+        e
+    };
+
+    e.get::<&Position>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "expected 8 bytes, got 4")]
+fn entity_set_ptr_w_size_mismatch() {
+    let world = World::new();
+    let position = world.component::<Position>();
+
+    let value = 10i32;
+    unsafe {
+        world.entity().set_ptr_w_size(
+            position,
+            std::mem::size_of::<i32>(),
+            &value as *const i32 as *const c_void,
+        );
+    }
+}
+
+#[test]
+fn entity_doc_name_falls_back_to_name() {
+    use flecs_ecs::addons::doc::Doc;
+
+    let world = World::new();
+
+    let named = world.entity_named("Foo");
+    assert_eq!(named.get_doc_name(), Some("Foo"));
+    assert_eq!(world.get_doc_name_id(named), Some("Foo"));
+
+    named.set_doc_name("Foo Bar");
+    assert_eq!(named.get_doc_name(), Some("Foo Bar"));
+
+    let unnamed = world.entity();
+    assert_eq!(unnamed.get_doc_name(), None);
+}
@@ -134,3 +134,52 @@ fn enum_standard_enum_reflection() {
     assert!(StandardEnum::Red.is_field_registered_as_entity());
     assert_eq!(red.path().unwrap(), "::flecs::enum_test::StandardEnum::Red");
 }
+
+#[test]
+fn enum_add_enum_replaces_constant() {
+    let world = World::new();
+
+    let e = world.entity().add_enum(StandardEnum::Red);
+    assert!(e.has_enum(StandardEnum::Red));
+    assert_eq!(
+        e.target::<StandardEnum>(0).unwrap(),
+        StandardEnum::Red.id_variant(&world)
+    );
+
+    e.add_enum(StandardEnum::Blue);
+    assert!(e.has_enum(StandardEnum::Blue));
+    assert!(!e.has_enum(StandardEnum::Red));
+    assert_eq!(
+        e.target_id_count(world.component::<StandardEnum>()),
+        Some(1)
+    );
+    assert_eq!(
+        e.target::<StandardEnum>(0).unwrap(),
+        StandardEnum::Blue.id_variant(&world)
+    );
+}
+
+#[test]
+fn enum_get_enum_round_trip() {
+    let world = World::new();
+
+    // register the variants out of declaration order
+    world.entity().add_enum(StandardEnum::Blue);
+
+    let e = world.entity();
+    assert_eq!(e.get_enum::<StandardEnum>(), None);
+
+    for variant in [StandardEnum::Green, StandardEnum::Red, StandardEnum::Blue] {
+        let expected = variant.enum_index();
+        e.add_enum(variant);
+        assert_eq!(
+            e.get_enum::<StandardEnum>().map(|v| v.enum_index()),
+            Some(expected)
+        );
+    }
+
+    assert_eq!(e.get_enum::<StandardEnum>(), Some(StandardEnum::Blue));
+
+    e.remove::<(StandardEnum, flecs::Wildcard)>();
+    assert_eq!(e.get_enum::<StandardEnum>(), None);
+}
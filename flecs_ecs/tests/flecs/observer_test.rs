@@ -450,6 +450,30 @@ fn observer_yield_existing() {
     });
 }
 
+#[test]
+fn observer_yield_existing_on_set() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world.entity().set(Position { x: i, y: i });
+    }
+    world.entity().add::<TagA>();
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .yield_existing()
+        .each_entity(|e, _pos| {
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 5));
+
+    world.entity().set(Position { x: 5, y: 5 });
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 6));
+}
+
 #[test]
 fn observer_yield_existing_2_terms() {
     let world = World::new();
@@ -950,3 +974,46 @@ fn observer_name_from_root() {
     let ns = world.entity_named("::ns");
     assert!(ns == o.parent().unwrap());
 }
+
+#[test]
+fn observer_custom_event_payload() {
+    #[derive(Component)]
+    struct DamageEvent {
+        amount: i32,
+    }
+
+    #[derive(Component)]
+    struct Health(i32);
+
+    let world = World::new();
+
+    world
+        .observer::<DamageEvent, &mut Health>()
+        .each_iter(|it, _, health| {
+            health.0 -= it.param().amount;
+        });
+
+    let e = world.entity().set(Health(10));
+    let other = world.entity().set(Health(10));
+
+    world
+        .event()
+        .add::<Health>()
+        .entity(e)
+        .emit(&DamageEvent { amount: 5 });
+
+    e.get::<&Health>(|health| assert_eq!(health.0, 5));
+    other.get::<&Health>(|health| assert_eq!(health.0, 10));
+
+    // entity events are emitted for any id, which entity observers receive
+    let received = std::rc::Rc::new(std::cell::Cell::new(0));
+    let received_clone = received.clone();
+    e.observe_payload(move |event: &DamageEvent| {
+        received_clone.set(received_clone.get() + event.amount);
+    });
+
+    e.emit(&DamageEvent { amount: 3 });
+    other.emit(&DamageEvent { amount: 3 });
+
+    assert_eq!(received.get(), 3);
+}
@@ -338,6 +338,50 @@ fn query_builder_type_pair_term() {
     assert_eq!(count, 2);
 }
 
+#[test]
+fn query_builder_get_var_by_name_parent() {
+    let world = World::new();
+
+    let p1 = world.entity().set(Mass { value: 1 });
+    let p2 = world.entity().set(Mass { value: 2 });
+    let p3 = world.entity();
+
+    let c1 = world.entity().child_of_id(p1).add::<TagA>();
+    let c2 = world.entity().child_of_id(p2).add::<TagA>();
+    let c3 = world.entity().child_of_id(p2).add::<TagA>();
+    world.entity().child_of_id(p3).add::<TagA>();
+
+    // TagA($this), ChildOf($this, $parent), Mass($parent)
+    let q = world
+        .query::<()>()
+        .with::<TagA>()
+        .with::<flecs::ChildOf>()
+        .second()
+        .set_var("parent")
+        .with::<&Mass>()
+        .src()
+        .set_var("parent")
+        .build();
+
+    let mut results = Vec::new();
+    q.each_iter(|it, index, ()| {
+        let parent = it.get_var_by_name("parent");
+        let mut mass = 0;
+        parent.get::<&Mass>(|m| mass = m.value);
+        results.push((it.entity(index).id(), parent.id(), mass));
+    });
+
+    results.sort();
+    assert_eq!(
+        results,
+        vec![
+            (c1.id(), p1.id(), 1),
+            (c2.id(), p2.id(), 2),
+            (c3.id(), p2.id(), 2)
+        ]
+    );
+}
+
 #[test]
 fn query_builder_pair_term_w_var() {
     let world = World::new();
@@ -2147,6 +2191,64 @@ unsafe extern "C" fn group_by_rel(
     0
 }
 
+#[test]
+fn query_builder_group_by_group_entity() {
+    #[derive(Component)]
+    struct Group;
+
+    #[derive(Component)]
+    struct First;
+
+    #[derive(Component)]
+    struct Second;
+
+    #[derive(Component)]
+    struct Third;
+
+    let world = World::new();
+
+    world.component::<First>();
+    world.component::<Second>();
+    world.component::<Third>();
+
+    let q = world.query::<&Position>().group_by::<Group>().build();
+
+    world
+        .entity()
+        .add::<(Group, Third)>()
+        .set(Position { x: 1, y: 1 });
+    world
+        .entity()
+        .add::<(Group, Second)>()
+        .set(Position { x: 2, y: 2 });
+    world
+        .entity()
+        .add::<(Group, First)>()
+        .set(Position { x: 3, y: 3 });
+    world
+        .entity()
+        .add::<(Group, Second)>()
+        .set(Position { x: 5, y: 5 })
+        .add::<Tag>();
+
+    let mut groups = vec![];
+    q.run_iter(|it, pos| {
+        let group = it.group_entity();
+        assert_eq!(group.id(), it.group_id());
+        groups.push((group.name().to_string(), pos[0].x));
+    });
+
+    assert_eq!(
+        groups,
+        [
+            ("First".to_string(), 3),
+            ("Second".to_string(), 2),
+            ("Second".to_string(), 5),
+            ("Third".to_string(), 1)
+        ]
+    );
+}
+
 #[test]
 fn query_builder_group_by_iter_one() {
     let world = World::new();
@@ -3412,6 +3514,41 @@ fn query_builder_operator_shortcuts() {
     assert_eq!(t.oper(), OperKind::NotFrom);
 }
 
+#[test]
+fn query_builder_and_from_or_from_prefab() {
+    #[derive(Component)]
+    struct TagA;
+
+    #[derive(Component)]
+    struct TagB;
+
+    #[derive(Component)]
+    struct TagC;
+
+    let world = World::new();
+
+    let bundle = world.prefab().add::<TagA>().add::<TagB>().add::<TagC>();
+
+    let all = world.entity().add::<TagA>().add::<TagB>().add::<TagC>();
+    let some = world.entity().add::<TagA>().add::<TagB>();
+    let none = world.entity().add::<Position>();
+
+    let query = world.query::<()>().with_id(bundle).and_from().build();
+
+    let mut matched = Vec::new();
+    query.each_entity(|e, _| matched.push(e.id()));
+    assert_eq!(matched, [all.id()]);
+
+    let query = world.query::<()>().with_id(bundle).or_from().build();
+
+    let mut matched = Vec::new();
+    query.each_entity(|e, _| matched.push(e.id()));
+    assert_eq!(matched.len(), 2);
+    assert!(matched.contains(&all.id()));
+    assert!(matched.contains(&some.id()));
+    assert!(!matched.contains(&none.id()));
+}
+
 #[test]
 fn query_builder_inout_shortcuts() {
     let world = World::new();
@@ -4958,3 +5095,84 @@ fn query_builder_scope() {
 
     assert_eq!(count, 3);
 }
+
+#[test]
+fn query_builder_named_lookup() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+    world.entity().set(Velocity { x: 0, y: 0 });
+
+    let q = world.query_named::<&Position>("movers").build();
+
+    let mut expected = vec![];
+    q.each_entity(|e, _| expected.push(e.id()));
+    assert_eq!(expected, vec![e1.id(), e2.id()]);
+
+    let found = world.query_lookup("movers");
+    assert_eq!(found.entity(), q.entity());
+
+    let mut actual = vec![];
+    found.run(|mut it| {
+        while it.next() {
+            let p = it.field::<Position>(0).unwrap();
+            for i in it.iter() {
+                assert_eq!(p[i].y, p[i].x + 1);
+                actual.push(it.entity(i).id());
+            }
+        }
+    });
+    assert_eq!(actual, expected);
+
+    assert!(world.try_query_lookup("jumpers").is_none());
+    assert!(world.try_query_lookup(&e1.path().unwrap()).is_none());
+}
+
+#[test]
+fn query_builder_second_wildcard() {
+    let world = World::new();
+
+    let parent_a = world.entity();
+    let parent_b = world.entity();
+    let child_a1 = world.entity().child_of_id(parent_a);
+    let child_a2 = world.entity().child_of_id(parent_a);
+    let child_b = world.entity().child_of_id(parent_b);
+    world.entity();
+
+    let q = world
+        .query::<()>()
+        .with::<flecs::ChildOf>()
+        .second_wildcard()
+        .build();
+
+    assert_eq!(
+        q.term(0).id(),
+        ecs_pair(flecs::ChildOf::ID, flecs::Wildcard::ID)
+    );
+
+    let mut found = vec![];
+    q.run(|mut it| {
+        while it.next() {
+            let pair = it.pair(0).unwrap();
+            assert_eq!(pair.first_id(), flecs::ChildOf::ID);
+            let parent = pair.second_id();
+            if parent != parent_a && parent != parent_b {
+                // skip builtin entities, which are children of the flecs module
+                continue;
+            }
+            for i in it.iter() {
+                found.push((it.entity(i).id(), parent.id()));
+            }
+        }
+    });
+
+    found.sort();
+    let mut expected = vec![
+        (child_a1.id(), parent_a.id()),
+        (child_a2.id(), parent_a.id()),
+        (child_b.id(), parent_b.id()),
+    ];
+    expected.sort();
+    assert_eq!(found, expected);
+}
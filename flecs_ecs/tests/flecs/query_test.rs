@@ -56,3 +56,1035 @@ fn query_iter_stage() {
 
     world.progress();
 }
+
+#[test]
+fn query_iter_entity_at_row() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    let world = World::new();
+
+    let entities: Vec<Entity> = (0..3)
+        .map(|i| world.entity().set(Position { x: i, y: i }).id())
+        .collect();
+
+    let query = world.new_query::<&Position>();
+
+    let mut count = 0;
+    query.run_iter(|it, p| {
+        for i in it.iter() {
+            let e = it.entity(i);
+            assert_eq!(e, entities[p[i].x as usize]);
+            assert_eq!(p[i].y, p[i].x);
+            count += 1;
+        }
+    });
+
+    assert_eq!(count, entities.len());
+}
+
+#[test]
+fn query_iter_entities_collect() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let mut entities: Vec<Entity> = (0..5)
+        .map(|i| world.entity().set(Position { x: i, y: i }).id())
+        .collect();
+
+    // spread the entities over two tables
+    entities.extend((5..8).map(|i| {
+        world
+            .entity()
+            .set(Position { x: i, y: i })
+            .add::<Tag>()
+            .id()
+    }));
+
+    let query = world.new_query::<&Position>();
+
+    let mut count = 0;
+    let mut iter = query.iter_entities();
+    while let Some((e, p)) = iter.next() {
+        assert_eq!(e, entities[p.x as usize]);
+        assert_eq!(p.y, p.x);
+        count += 1;
+    }
+    assert_eq!(count, entities.len());
+
+    // dropping a partially consumed iterator unlocks the table
+    assert!(query.iter_entities().next().is_some());
+    world.entity().set(Position { x: 8, y: 8 });
+
+    assert_eq!(query.iter_entities().entities().count(), entities.len() + 1);
+}
+
+#[test]
+fn query_iter_entities_mut() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let a = world.entity().set(Position { x: 1 });
+    let b = world.entity().set(Position { x: 2 });
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut iter = query.iter_entities();
+    while let Some((_, p)) = iter.next() {
+        p.x *= 10;
+    }
+    drop(iter);
+
+    // the table is unlocked once the iterator is dropped
+    a.add::<Tag>();
+    a.get::<&Position>(|p| assert_eq!(p.x, 10));
+    b.get::<&Position>(|p| assert_eq!(p.x, 20));
+}
+
+#[test]
+fn query_run_iter_empty_table() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1 })
+        .set(Velocity { x: 2 })
+        .add::<Tag>();
+
+    let query = world
+        .query::<(&Position, Option<&Velocity>, &Tag)>()
+        .set_cached()
+        .query_flags(QueryFlags::MatchEmptyTables)
+        .build();
+
+    // leave the (Position, Velocity, Tag) table empty
+    e.remove::<Velocity>();
+
+    let mut rows = 0;
+    let mut empty_tables = 0;
+    query.run_iter(|it, (pos, vel, tag)| {
+        assert_eq!(pos.len(), it.count());
+        assert_eq!(tag.len(), it.count());
+        if let Some(vel) = vel {
+            assert_eq!(vel.len(), it.count());
+        }
+        if it.count() == 0 {
+            empty_tables += 1;
+        }
+        rows += it.count();
+    });
+
+    assert_eq!(empty_tables, 1);
+    assert_eq!(rows, 1);
+}
+
+#[test]
+fn query_run_skip_empty_tables() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+
+    world.entity().set(Position { x: 1 });
+    let e = world.entity().set(Position { x: 2 }).set(Velocity { x: 3 });
+
+    let query = world
+        .query::<(&Position, Option<&Velocity>)>()
+        .set_cached()
+        .query_flags(QueryFlags::MatchEmptyTables)
+        .build();
+
+    // leave the (Position, Velocity) table empty
+    e.remove::<Velocity>();
+
+    let mut tables = 0;
+    let mut skipped = 0;
+    let mut sum = 0;
+    query.run(|mut it| {
+        while it.next() {
+            tables += 1;
+            if it.is_empty() {
+                assert_eq!(it.count(), 0);
+                skipped += 1;
+                continue;
+            }
+            let pos = it.field::<Position>(0).unwrap();
+            for i in it.iter() {
+                sum += pos[i].x;
+            }
+        }
+    });
+
+    assert_eq!(tables, 2);
+    assert_eq!(skipped, 1);
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn query_run_iter_field_is_self_prefab() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Mass {
+        value: i32,
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Mass>()
+        .add_id((*flecs::OnInstantiate, *flecs::Inherit));
+
+    let prefab = world.prefab().set(Mass { value: 10 });
+    world.entity().is_a_id(prefab).set(Position { x: 1 });
+    world.entity().is_a_id(prefab).set(Position { x: 2 });
+    world
+        .entity()
+        .set(Position { x: 3 })
+        .set(Mass { value: 20 });
+
+    let query = world.new_query::<(&Position, &Mass)>();
+
+    let mut shared_rows = 0;
+    let mut owned_rows = 0;
+    query.run_iter(|it, (pos, mass)| {
+        assert!(it.is_self(0));
+        if it.is_self(1) {
+            assert_eq!(mass.len(), it.count());
+            owned_rows += it.count();
+        } else {
+            assert_eq!(it.src(1), prefab);
+            for i in it.iter() {
+                assert_eq!(mass[0].value, 10);
+                assert!(pos[i].x < 3);
+            }
+            shared_rows += it.count();
+        }
+    });
+
+    assert_eq!(shared_rows, 2);
+    assert_eq!(owned_rows, 1);
+}
+
+#[test]
+fn query_run_field_by_index() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+
+    world.entity().set(Position { x: 1 }).set(Velocity { x: 2 });
+    world.entity().set(Position { x: 3 }).set(Velocity { x: 4 });
+
+    let query = world
+        .query::<()>()
+        .with::<&Position>()
+        .with::<&Velocity>()
+        .build();
+
+    let mut sum = 0;
+    query.run(|mut it| {
+        while it.next() {
+            let pos = it.field::<Position>(0).unwrap();
+            let vel = it.field::<Velocity>(1).unwrap();
+            for i in it.iter() {
+                sum += pos[i].x + vel[i].x;
+            }
+
+            // wrong type or out of bounds
+            assert!(it.field::<Velocity>(0).is_none());
+            assert!(it.field::<Position>(2).is_none());
+            assert!(it.field::<Position>(-1).is_none());
+        }
+    });
+
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn query_iter_flags_instanced() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Mass {
+        value: i32,
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Mass>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let base = world.prefab().set(Mass { value: 10 });
+    world.entity().is_a_id(base).set(Position { x: 1 });
+    world.entity().is_a_id(base).set(Position { x: 2 });
+
+    let instanced = world.query::<(&Position, &Mass)>().instanced().build();
+    let not_instanced = world.query::<(&Position, &Mass)>().build();
+
+    instanced.run(|mut it| {
+        assert!(!it.is_valid());
+        while it.next() {
+            assert!(it.is_valid());
+            assert!(it.is_instanced());
+            assert!(it.is_self(0));
+            assert!(!it.is_self(1));
+            assert_eq!(it.count(), 2);
+        }
+    });
+
+    not_instanced.run(|mut it| {
+        while it.next() {
+            assert!(!it.is_instanced());
+        }
+    });
+
+    // shared fields are read through the same pointer for every entity
+    let mut sum = 0;
+    instanced.each(|(pos, mass)| {
+        sum += pos.x * mass.value;
+    });
+    assert_eq!(sum, 30);
+}
+
+#[test]
+fn query_instanced_vs_not_instanced_shared() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Mass {
+        value: i32,
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Mass>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let base = world.prefab().set(Mass { value: 10 });
+    world.entity().is_a_id(base).set(Position { x: 1 });
+    world.entity().is_a_id(base).set(Position { x: 2 });
+    world.entity().is_a_id(base).set(Position { x: 3 });
+    world.entity().set(Mass { value: 2 }).set(Position { x: 4 });
+
+    let instanced = world.query::<(&Position, &Mass)>().instanced().build();
+    let not_instanced = world.query::<(&Position, &Mass)>().build();
+
+    let mut results = 0;
+    let mut sum = 0;
+    instanced.run_iter(|it, (pos, mass)| {
+        results += 1;
+        assert_eq!(pos.len(), it.count());
+        if it.is_self(1) {
+            assert_eq!(mass.len(), it.count());
+            for i in it.iter() {
+                sum += pos[i].x * mass[i].value;
+            }
+        } else {
+            assert_eq!(mass.len(), 1);
+            for i in it.iter() {
+                sum += pos[i].x * mass[0].value;
+            }
+        }
+    });
+    assert_eq!(results, 2);
+    assert_eq!(sum, 68);
+
+    let mut results = 0;
+    let mut sum = 0;
+    not_instanced.run_iter(|it, (pos, mass)| {
+        results += 1;
+        for i in it.iter() {
+            sum += pos[i].x * mass[i].value;
+        }
+    });
+    assert_eq!(results, 4);
+    assert_eq!(sum, 68);
+}
+
+#[test]
+fn query_sparse_component() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+
+    world.component::<Velocity>().sparse();
+
+    let mut expected = 0;
+    let mut with_velocity = Vec::new();
+    for i in 0..100 {
+        let e = world.entity().set(Position { x: i });
+        if i % 25 == 0 {
+            e.set(Velocity { x: i });
+            expected += 2 * i;
+            with_velocity.push(e);
+        }
+    }
+
+    // sparse components are part of the table type, but have no table column
+    let table = with_velocity[0].table().unwrap();
+    assert!(table.has_type::<Velocity>());
+    assert!(table.find_column_index::<Velocity>().is_none());
+    assert!(table.find_column_index::<Position>().is_some());
+
+    let query = world.new_query::<(&Position, &mut Velocity)>();
+
+    let mut count = 0;
+    let mut sum = 0;
+    query.each(|(pos, vel)| {
+        count += 1;
+        sum += pos.x + vel.x;
+        vel.x += 1;
+    });
+    assert_eq!(count, 4);
+    assert_eq!(sum, expected);
+
+    let mut sum = 0;
+    query.each_entity(|e, (_pos, vel)| {
+        e.get::<&Velocity>(|v| assert_eq!(v.x, vel.x));
+        sum += vel.x;
+    });
+    assert_eq!(sum, expected / 2 + 4);
+
+    let mut sum = 0;
+    query.run_iter(|it, (pos, vel)| {
+        // results with sparse fields are returned one entity at a time
+        assert_eq!(it.count(), 1);
+        assert_eq!(pos.len(), 1);
+        assert_eq!(vel.len(), 1);
+        for i in it.iter() {
+            sum += pos[i].x + vel[i].x;
+        }
+    });
+    assert_eq!(sum, expected + 4);
+}
+
+#[test]
+fn query_matches_table() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+        y: i32,
+    }
+
+    let world = World::new();
+
+    let pos_vel = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+    let pos = world.entity().set(Position { x: 5, y: 6 });
+
+    let query = world.new_query::<(&Position, &Velocity)>();
+
+    assert!(query.matches_table(pos_vel.table().unwrap()));
+    assert!(!query.matches_table(pos.table().unwrap()));
+}
+
+#[test]
+fn query_iter_table_range() {
+    #[derive(Component)]
+    struct Value {
+        index: i32,
+        touched: bool,
+    }
+
+    let world = World::new();
+
+    let entities: Vec<_> = (0..10)
+        .map(|index| {
+            world.entity().set(Value {
+                index,
+                touched: false,
+            })
+        })
+        .collect();
+
+    let query = world.new_query::<&mut Value>();
+    let range = TableRange::new(entities[0].table().unwrap(), 2, 3);
+
+    query.iterable().set_range(range).each(|v| v.touched = true);
+
+    for e in &entities {
+        e.get::<&Value>(|v| assert_eq!(v.touched, (2..5).contains(&v.index)));
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "ECS_LOCKED_STORAGE")]
+fn query_each_add_component_locked() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+    world.entity().set(Position { x: 1 });
+
+    let query = world.new_query::<&Position>();
+    query.each_entity(|e, _| {
+        e.set(Velocity { x: 1 });
+    });
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "ECS_LOCKED_STORAGE")]
+fn query_each_override_inherited_component_locked() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+    world.component::<Velocity>().on_instantiate_inherit();
+
+    let base = world.prefab().set(Velocity { x: 1 });
+    world.entity().is_a_id(base).set(Position { x: 1 });
+
+    // the entity has Velocity through its base, setting it adds an override which
+    // moves the entity to another table
+    let query = world.new_query::<&Position>();
+    query.each_entity(|e, _| {
+        e.set(Velocity { x: 2 });
+    });
+}
+
+#[test]
+fn query_each_add_component_deferred() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+    let e = world.entity().set(Position { x: 1 });
+
+    let query = world.new_query::<&Position>();
+    world.defer(|| {
+        query.each_entity(|e, pos| {
+            e.set(Velocity { x: pos.x });
+        });
+    });
+
+    assert!(e.has::<Velocity>());
+}
+
+#[test]
+fn query_field_at_sparse() {
+    #[derive(Component)]
+    struct Health {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    let world = World::new();
+    world.component::<Health>().add_trait::<flecs::Sparse>();
+
+    for i in 1..=3 {
+        world
+            .entity()
+            .set(Health { value: i * 10 })
+            .set(Position { x: i });
+    }
+
+    let query = world
+        .query::<()>()
+        .with::<&Health>()
+        .with::<&Position>()
+        .build();
+
+    let mut visited = 0;
+    query.run(|mut it| {
+        while it.next() {
+            let it = it.iter_mut() as *const flecs_ecs::sys::ecs_iter_t;
+            for row in 0..unsafe { (*it).count } {
+                let health = unsafe { &*ecs_field_at::<Health>(it, 0, row) };
+                let pos = unsafe { &*ecs_field_at::<Position>(it, 1, row) };
+                assert_eq!(health.value, pos.x * 10);
+                visited += 1;
+            }
+        }
+    });
+
+    assert_eq!(visited, 3);
+}
+
+#[test]
+fn query_pair_data_each() {
+    #[derive(Component)]
+    struct Amount {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct Apples;
+
+    #[derive(Component)]
+    struct Pears;
+
+    let world = World::new();
+
+    let entities: Vec<_> = (1..=3)
+        .map(|i| {
+            world
+                .entity()
+                .set_pair::<Amount, Apples>(Amount { value: i })
+                .set_pair::<Amount, Pears>(Amount { value: i * 10 })
+        })
+        .collect();
+
+    // an entity in a different table
+    world
+        .entity()
+        .set_pair::<Amount, Apples>(Amount { value: 4 })
+        .add::<Pears>();
+
+    let query = world.new_query::<(&mut (Amount, Apples), &(Amount, Pears))>();
+
+    let mut count = 0;
+    query.each(|(apples, pears)| {
+        assert_eq!(apples.value * 10, pears.value);
+        apples.value += pears.value;
+        count += 1;
+    });
+    assert_eq!(count, 3);
+
+    for (i, e) in entities.iter().enumerate() {
+        let i = i as i32 + 1;
+        e.get::<&(Amount, Apples)>(|apples| assert_eq!(apples.value, i * 11));
+    }
+}
+
+#[test]
+fn query_run_break_early_fini() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    let world = World::new();
+    world.entity().set(Position { x: 1 });
+    world.entity().set(Position { x: 2 }).add::<flecs::Prefab>();
+
+    let q = world
+        .query::<&Position>()
+        .with::<flecs::Prefab>()
+        .optional()
+        .build();
+
+    // leave the iterator unfinished, or never start it
+    for _ in 0..3 {
+        q.run(|mut it| {
+            it.next();
+        });
+    }
+    q.run(|_it| {});
+    q.run(|it| it.fini());
+
+    // stops at the first match instead of visiting the remaining tables
+    let found = q.find(|p| p.x > 0).unwrap();
+    found.get::<&Position>(|p| assert_eq!(p.x, 1));
+
+    // the tables are unlocked and the iterator stack is intact
+    let e = world.entity().set(Position { x: 3 });
+    e.remove::<Position>();
+
+    let mut total = 0;
+    q.each(|p| total += p.x);
+    assert_eq!(total, 3);
+}
+
+#[test]
+fn query_page() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let mut entities = vec![];
+    for i in 0..100 {
+        let e = world.entity().set(Position { x: i });
+        // spread the results over two tables
+        if i >= 12 {
+            e.add::<Tag>();
+        }
+        entities.push(e.id());
+    }
+
+    let q = world.query::<&Position>().build();
+
+    let mut all = vec![];
+    q.each_entity(|e, _| all.push(e.id()));
+    assert_eq!(all.len(), 100);
+
+    let mut visited = vec![];
+    let mut tables = 0;
+    q.page(10, 5, |it, pos| {
+        tables += 1;
+        for i in it.iter() {
+            visited.push(it.entity(i).id());
+            assert_eq!(
+                pos[i].x,
+                entities
+                    .iter()
+                    .position(|e| *e == it.entity(i).id())
+                    .unwrap() as i32
+            );
+        }
+    });
+
+    assert_eq!(visited, all[10..15]);
+    assert_eq!(tables, 2);
+
+    let mut count = 0;
+    q.page(95, 10, |it, _| count += it.count());
+    assert_eq!(count, 5);
+
+    // the query can still be iterated after paging
+    let mut count = 0;
+    q.run_iter(|it, _| count += it.count());
+    assert_eq!(count, 100);
+}
+
+#[test]
+fn query_override_inherited_is_self() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Position>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let base = world.prefab().set(Position { x: 1, y: 2 });
+    let inst = world.entity().is_a_id(base);
+
+    let query = world.new_query::<&Position>();
+
+    let mut matched = 0;
+    query.run_iter(|it, pos| {
+        assert!(!it.is_self(0));
+        assert_eq!(it.src(0), base);
+        assert_eq!((pos[0].x, pos[0].y), (1, 2));
+        matched += it.count();
+    });
+    assert_eq!(matched, 1);
+
+    inst.override_type::<Position>();
+
+    let mut matched = 0;
+    query.run_iter(|it, pos| {
+        assert!(it.is_self(0));
+        assert_eq!(it.entity(0), inst);
+        assert_eq!((pos[0].x, pos[0].y), (1, 2));
+        matched += it.count();
+    });
+    assert_eq!(matched, 1);
+
+    inst.set(Position { x: 10, y: 20 });
+    base.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 2)));
+}
+
+#[test]
+fn query_optional_middle_term_layout() {
+    #[derive(Component)]
+    struct A {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct B {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct C {
+        value: i32,
+    }
+
+    let world = World::new();
+
+    let without_b = world.entity().set(A { value: 1 }).set(C { value: 3 });
+    let with_b = world
+        .entity()
+        .set(A { value: 10 })
+        .set(B { value: 20 })
+        .set(C { value: 30 });
+
+    let query = world.new_query::<(&A, Option<&B>, &C)>();
+
+    let mut rows = 0;
+    query.each_entity(|e, (a, b, c)| {
+        if e == without_b {
+            assert_eq!(a.value, 1);
+            assert!(b.is_none());
+            assert_eq!(c.value, 3);
+        } else {
+            assert_eq!(e, with_b);
+            assert_eq!(a.value, 10);
+            assert_eq!(b.unwrap().value, 20);
+            assert_eq!(c.value, 30);
+        }
+        rows += 1;
+    });
+    assert_eq!(rows, 2);
+
+    let mut tables = 0;
+    query.run_iter(|it, (a, b, c)| {
+        assert_eq!(it.count(), 1);
+        match b {
+            None => assert_eq!((a[0].value, c[0].value), (1, 3)),
+            Some(b) => assert_eq!((a[0].value, b[0].value, c[0].value), (10, 20, 30)),
+        }
+        tables += 1;
+    });
+    assert_eq!(tables, 2);
+
+    // a missing optional field also does not shift mutable fields after it
+    world
+        .new_query::<(&A, Option<&mut B>, &mut C)>()
+        .each(|(a, b, c)| {
+            if let Some(b) = b {
+                b.value += 1;
+            }
+            c.value += a.value;
+        });
+
+    without_b.get::<&C>(|c| assert_eq!(c.value, 4));
+    with_b.get::<(&B, &C)>(|(b, c)| assert_eq!((b.value, c.value), (21, 40)));
+}
+
+#[test]
+fn query_destruct_in_loop() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    let world = World::new();
+    world.entity().set(Position { x: 1 });
+    world.entity().set(Position { x: 2 });
+
+    let queries = world.count::<(flecs::Poly, flecs::Query)>();
+
+    for _ in 0..100 {
+        let cached = world.query::<&Position>().set_cached().build();
+        let mut sum = 0;
+        cached.each(|p| sum += p.x);
+        assert_eq!(sum, 3);
+        cached.destruct();
+
+        let mut uncached = world.new_query::<&Position>();
+        assert_eq!(uncached.count(), 2);
+        uncached.destruct();
+    }
+
+    assert_eq!(world.count::<(flecs::Poly, flecs::Query)>(), queries);
+
+    // dropping a cached query keeps it alive until the world is deleted
+    drop(world.query::<&Position>().set_cached().build());
+    assert_eq!(world.count::<(flecs::Poly, flecs::Query)>(), queries + 1);
+}
+
+#[test]
+fn query_iter_table() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    let world = World::new();
+    let pos = world.entity().set(Position { x: 1 });
+    let pos_vel = world.entity().set(Position { x: 2 }).set(Velocity { x: 3 });
+
+    let pos_id = world.component_id::<Position>();
+    let vel_id = world.component_id::<Velocity>();
+
+    let mut tables = 0;
+    world.new_query::<&Position>().run(|mut it| {
+        while it.next() {
+            let table = it.table().unwrap();
+            let ids: Vec<u64> = table.archetype().as_slice().iter().map(|id| **id).collect();
+            assert_eq!(table.count(), it.count() as i32);
+            assert!(ids.contains(&*pos_id));
+
+            for i in it.iter() {
+                let e = it.entity(i);
+                if e == pos {
+                    assert_eq!(ids, vec![*pos_id]);
+                    assert_eq!(table.find_type_index::<Velocity>(), None);
+                } else {
+                    assert_eq!(e, pos_vel);
+                    assert!(ids.contains(&*vel_id));
+                    assert!(table.find_type_index::<Velocity>().is_some());
+                }
+                assert_eq!(table.table_ptr_mut(), e.table().unwrap().table_ptr_mut());
+            }
+            tables += 1;
+        }
+    });
+
+    assert_eq!(tables, 2);
+}
+
+#[test]
+fn query_without_and_with_matching() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    struct Frozen;
+
+    let world = World::new();
+
+    let pos_only = world.entity().set(Position { x: 1 });
+    let moving = world.entity().set(Position { x: 2 }).set(Velocity { x: 1 });
+    let frozen = world.entity().set(Position { x: 3 }).add::<Frozen>();
+    let frozen_moving = world
+        .entity()
+        .set(Position { x: 4 })
+        .set(Velocity { x: 1 })
+        .add::<Frozen>();
+    let frozen_only = world.entity().add::<Frozen>();
+
+    let positions = world.new_query::<&Position>();
+    let frozen_query = world.new_query::<&Frozen>();
+
+    let mut difference: Vec<(Entity, i32)> = vec![];
+    let mut iter = positions.without_matching(&frozen_query);
+    while let Some((e, p)) = iter.next() {
+        difference.push((e.id(), p.x));
+    }
+    difference.sort();
+    assert_eq!(difference, vec![(pos_only.id(), 1), (moving.id(), 2)]);
+
+    let mut intersection: Vec<Entity> = positions
+        .with_matching(&frozen_query)
+        .entities()
+        .map(|e| e.id())
+        .collect();
+    intersection.sort();
+    assert_eq!(intersection, vec![frozen.id(), frozen_moving.id()]);
+
+    // the other query doesn't need to share terms with this one
+    let velocities = world.new_query::<&Velocity>();
+    let mut frozen_still: Vec<Entity> = frozen_query
+        .without_matching(&velocities)
+        .entities()
+        .map(|e| e.id())
+        .collect();
+    frozen_still.sort();
+    assert_eq!(frozen_still, vec![frozen.id(), frozen_only.id()]);
+}
@@ -6958,3 +6958,197 @@ fn system_randomize_timers() {
 //                     assert_eq!(c.0,2);
 //                 });
 // }
+
+#[test]
+fn system_run_system_manually() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    let target = world.entity().set(Position { x: 30, y: 40 });
+
+    let system = world.system::<&Position>().each_iter(|mut it, index, pos| {
+        assert!((it.delta_time() - 0.5).abs() < FTime::EPSILON);
+        let world = it.world();
+        world.get::<&mut Count>(|count| count.0 += 1);
+
+        let x = unsafe { *(it.iter_mut().param as *const i32) };
+        if pos.x == x {
+            let entity = it.entity(index);
+            it.set_interrupted_by(entity);
+        }
+    });
+
+    world.set(Count(0));
+
+    let mut x = 30;
+    let found = world.run_system(system, 0.5, &mut x as *mut i32 as *mut c_void);
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+    assert_eq!(found.map(|e| e.id()), Some(target.id()));
+}
+
+#[test]
+fn system_boxed_context() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct Counter {
+        count: usize,
+        dropped: Rc<Cell<bool>>,
+    }
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            self.dropped.set(true);
+        }
+    }
+
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    world.entity().set(Position { x: 30, y: 40 });
+
+    let dropped = Rc::new(Cell::new(false));
+
+    let system = world
+        .system::<&Position>()
+        .set_context_boxed(Box::new(Counter {
+            count: 0,
+            dropped: dropped.clone(),
+        }))
+        .run(|mut it| {
+            let counter = unsafe { it.context::<Counter>() };
+            while it.next() {
+                counter.count += it.count();
+            }
+        });
+
+    // a second system with its own context
+    let other = world
+        .system::<&Position>()
+        .set_context_boxed(Box::new(100usize))
+        .run(|mut it| {
+            let counter = unsafe { it.context::<usize>() };
+            while it.next() {
+                *counter += 1;
+            }
+        });
+
+    world.progress();
+    world.progress();
+    world.progress();
+
+    assert_eq!(unsafe { (*(system.context() as *const Counter)).count }, 6);
+    assert_eq!(unsafe { *(other.context() as *const usize) }, 103);
+
+    assert!(!dropped.get());
+    system.destruct();
+    assert!(dropped.get());
+}
+
+#[test]
+fn system_each_iter_delta_time_and_entity() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(Velocity { x: 1, y: 2 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 3, y: 4 });
+
+    world
+        .system::<(&mut Position, &Velocity)>()
+        .each_iter(|it, i, (p, v)| {
+            let dt = it.delta_time();
+            assert!((dt - 2.0).abs() < FTime::EPSILON);
+
+            let e = it.entity(i);
+            assert!(e.has::<Velocity>());
+
+            p.x += (v.x as f32 * dt) as i32;
+            p.y += (v.y as f32 * dt) as i32;
+        });
+
+    world.progress_time(2.0);
+
+    e1.get::<&Position>(|p| assert_eq!((p.x, p.y), (2, 4)));
+    e2.get::<&Position>(|p| assert_eq!((p.x, p.y), (16, 28)));
+}
+
+#[test]
+fn system_profiler_reports_each_system() {
+    use std::sync::{Arc, Mutex};
+
+    let world = World::new();
+
+    world.system_named::<&Position>("Fast").each(|_| {});
+    world
+        .system_named::<&Position>("Slow")
+        .each(|_| std::thread::sleep(std::time::Duration::from_millis(2)));
+    world.system_named::<&Velocity>("NoMatch").each(|_| {});
+
+    world.entity().set(Position { x: 0, y: 0 });
+
+    let timings = Arc::new(Mutex::new(Vec::new()));
+    let report = timings.clone();
+    world.set_system_profiler(move |name, micros| {
+        report.lock().unwrap().push((name.to_string(), micros));
+    });
+
+    world.progress();
+
+    let frame = timings.lock().unwrap().clone();
+    let timing = |name: &str| {
+        frame
+            .iter()
+            .find(|(n, _)| n == name)
+            .unwrap_or_else(|| panic!("{name} was not reported"))
+            .1
+    };
+    assert!(timing("Fast") >= 0.0);
+    assert!(timing("Slow") >= 2000.0);
+    assert!(timing("Slow") > timing("Fast"));
+
+    // times are reported per frame, not accumulated
+    timings.lock().unwrap().clear();
+    world.progress();
+    let frame = timings.lock().unwrap().clone();
+    let slow = frame.iter().find(|(n, _)| n == "Slow").unwrap().1;
+    assert!((2000.0..1_000_000.0).contains(&slow));
+
+    world.remove_system_profiler();
+    timings.lock().unwrap().clear();
+    world.progress();
+    assert!(timings.lock().unwrap().is_empty());
+}
+
+#[test]
+fn system_run_with_param() {
+    struct Params {
+        min_x: i32,
+    }
+
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    world.entity().set(Position { x: 30, y: 40 });
+    world.entity().set(Position { x: 50, y: 60 });
+
+    let system = world.system::<&Position>().run(|mut it| {
+        let params = unsafe { it.param_as::<Params>() }.expect("system was run with a param");
+        let mut count = 0;
+        while it.next() {
+            let p = it.field::<Position>(0).unwrap();
+            count += it.iter().filter(|&i| p[i].x >= params.min_x).count();
+        }
+        it.world().get::<&mut Count>(|c| c.0 += count as i32);
+    });
+
+    world.set(Count(0));
+
+    world.run_system_with_param(system, 0.0, &Params { min_x: 30 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+}
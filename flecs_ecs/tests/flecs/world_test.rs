@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use flecs_ecs::core::World;
+use crate::common_test::*;
 
 #[test]
 fn world_no_panic_clone_test() {
@@ -25,3 +25,681 @@ fn world_panic_lingering_query_handles() {
     let _query = world.new_query::<()>();
     std::mem::drop(world);
 }
+
+#[test]
+fn world_set_scope_restore_previous() {
+    let world = World::new();
+
+    let parent = world.entity();
+    assert!(world.get_scope().is_none());
+
+    let prev = world.set_scope_id(parent);
+    assert_eq!(prev, 0);
+    assert_eq!(world.get_scope().unwrap(), parent);
+
+    let child = world.entity();
+    assert_eq!(child.parent().unwrap(), parent);
+
+    world.set_scope_id(prev);
+    assert!(world.get_scope().is_none());
+
+    let root = world.entity();
+    assert!(root.parent().is_none());
+}
+
+#[test]
+fn world_each_pair_visits_all_instances() {
+    let world = World::new();
+
+    let targets = [world.entity(), world.entity()];
+    let subjects = [world.entity(), world.entity(), world.entity()];
+
+    for subject in subjects {
+        for target in targets {
+            subject.add_first::<Likes>(target);
+        }
+    }
+
+    // make sure other relationships aren't reported
+    subjects[0].add_first::<Eats>(targets[0]);
+
+    let mut visited = Vec::new();
+    world.each_pair::<Likes>(|subject, target| {
+        visited.push((subject.id(), target.id()));
+    });
+
+    assert_eq!(visited.len(), subjects.len() * targets.len());
+    for subject in subjects {
+        for target in targets {
+            assert!(visited.contains(&(subject.id(), target.id())));
+        }
+    }
+}
+
+#[test]
+fn world_async_stage_manual_merge() {
+    let world = World::new();
+
+    // components can't be registered from an async stage
+    world.component::<Likes>();
+    world.component::<Position>();
+
+    let e = world.entity();
+    let stage = world.create_async_stage();
+
+    e.mut_current_stage(stage)
+        .add::<Likes>()
+        .set(Position { x: 10, y: 20 });
+
+    // async stages are never merged automatically
+    world.progress();
+    assert!(!e.has::<Likes>());
+    assert!(!e.has::<Position>());
+
+    stage.merge();
+
+    assert!(e.has::<Likes>());
+    e.get::<&Position>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+}
+
+#[test]
+fn world_delete_empty_tables() {
+    let world = World::new();
+
+    let tags: Vec<EntityView> = (0..10).map(|_| world.entity()).collect();
+
+    // create one archetype per tag, then empty all of them
+    let entities: Vec<EntityView> = tags.iter().map(|tag| world.entity().add_id(*tag)).collect();
+    for e in entities {
+        e.destruct();
+    }
+
+    // the first call bumps the generation of the empty tables, the second deletes them
+    assert_eq!(world.delete_empty_tables(0, 0, 1, 0, 0.0), 0);
+    let deleted = world.delete_empty_tables(0, 0, 1, 0, 0.0);
+
+    assert!(deleted >= tags.len() as i32);
+
+    // deleted tables are recreated on demand
+    let e = world.entity().add_id(tags[0]);
+    assert!(e.has_id(tags[0]));
+}
+
+#[test]
+fn world_run_post_frame() {
+    let world = World::new();
+
+    let order = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let order_system = order.clone();
+
+    world.system::<()>().run(move |it| {
+        let first = order_system.clone();
+        let second = order_system.clone();
+        it.world()
+            .run_post_frame(move |_world| first.borrow_mut().push(1));
+        it.world()
+            .run_post_frame(move |_world| second.borrow_mut().push(2));
+        // callbacks only run once the frame has completed
+        assert_eq!(order_system.borrow().len() % 2, 0);
+    });
+
+    world.progress();
+    assert_eq!(*order.borrow(), vec![1, 2]);
+
+    world.progress();
+    assert_eq!(*order.borrow(), vec![1, 2, 1, 2]);
+}
+
+#[test]
+fn world_run_post_frame_dropped_with_world() {
+    let world = World::new();
+
+    let captured = std::rc::Rc::new(());
+    let in_callback = captured.clone();
+    world.run_post_frame(move |_world| drop(in_callback));
+    assert_eq!(std::rc::Rc::strong_count(&captured), 2);
+
+    // the frame never completes, so the callback is dropped with the world
+    drop(world);
+    assert_eq!(std::rc::Rc::strong_count(&captured), 1);
+}
+
+#[test]
+fn world_set_get_ctx() {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    struct Counter {
+        value: AtomicI32,
+    }
+
+    let world = World::new();
+    assert!(world.with_ctx(|_: &Counter| ()).is_none());
+
+    world.set_ctx(Box::new(Counter {
+        value: AtomicI32::new(0),
+    }));
+
+    world.system::<()>().run(|it| {
+        it.world().with_ctx(|counter: &Counter| {
+            counter.value.fetch_add(1, Ordering::Relaxed);
+        });
+    });
+
+    world.progress();
+    world.progress();
+
+    assert_eq!(
+        world.with_ctx(|counter: &Counter| counter.value.load(Ordering::Relaxed)),
+        Some(2)
+    );
+    assert!(world.with_ctx(|_: &i32| ()).is_none());
+
+    // replacing the context drops the previous one
+    let dropped = std::sync::Arc::new(());
+    world.set_ctx(Box::new(dropped.clone()));
+    assert_eq!(std::sync::Arc::strong_count(&dropped), 2);
+    world.set_ctx(Box::new(0));
+    assert_eq!(std::sync::Arc::strong_count(&dropped), 1);
+}
+
+#[test]
+#[should_panic(expected = "can't be replaced while it is borrowed")]
+fn world_set_ctx_while_borrowed() {
+    let world = World::new();
+    world.set_ctx(Box::new(String::from("hello")));
+
+    world.with_ctx(|s: &String| {
+        world.set_ctx(Box::new(0));
+        assert_eq!(s, "hello");
+    });
+}
+
+#[test]
+fn world_app_run_until_quit() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world
+        .system::<&mut Count>()
+        .term_at(0)
+        .singleton()
+        .each_iter(|it, _, count| {
+            count.0 += 1;
+            if count.0 == 3 {
+                it.world().quit();
+            }
+        });
+
+    let start = std::time::Instant::now();
+    let result = world.app().set_target_fps(0.0).run();
+
+    assert_eq!(result, 0);
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    world.get::<&Count>(|count| assert_eq!(count.0, 3));
+}
+
+#[test]
+#[should_panic]
+fn world_defer_end_without_begin() {
+    let world = World::new();
+    world.defer_end();
+}
+
+#[test]
+#[should_panic(expected = "cannot destroy the world while it is deferred")]
+fn world_drop_while_deferred() {
+    let world = World::new();
+    world.defer_begin();
+    drop(world);
+}
+
+#[test]
+fn world_defer_nested() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    world.defer(|| {
+        e.set(Position { x: 10, y: 20 });
+        world.defer(|| {
+            e.add::<TagA>();
+        });
+
+        // the inner defer doesn't flush the outer defer queue
+        assert!(world.is_deferred());
+        assert!(!e.has::<Position>());
+        assert!(!e.has::<TagA>());
+    });
+
+    assert!(!world.is_deferred());
+    assert!(e.has::<Position>());
+    assert!(e.has::<TagA>());
+}
+
+#[test]
+fn world_get_alive_restores_generation() {
+    let world = World::new();
+
+    // recycle an id so the new entity has a non-zero generation
+    let e = world.entity();
+    e.destruct();
+    let e = world.entity();
+    assert!(get_generation(e) > 0);
+
+    let stripped = strip_generation(e);
+    assert_ne!(stripped, *e.id());
+    assert_eq!(get_generation(stripped), 0);
+
+    let alive = world.try_get_alive(stripped).unwrap();
+    assert_eq!(alive, e);
+    assert_eq!(get_generation(alive), get_generation(e));
+    assert_eq!(world.get_alive(stripped), e);
+
+    e.destruct();
+    assert!(world.try_get_alive(stripped).is_none());
+    assert_eq!(world.get_alive(stripped).id(), 0);
+}
+
+#[test]
+fn world_make_alive_with_entity_range() {
+    let world = World::new();
+
+    world.set_entity_range(5000, 0);
+
+    // id 1234 with generation 3, as it could have been saved by a serializer
+    let id = Entity::new(ecs_entity_id_combine(1234, 3));
+    assert!(!world.is_alive(id));
+
+    let e = world.make_alive(id);
+    assert_eq!(e, id);
+    assert!(world.is_alive(id));
+    assert_eq!(get_generation(e), 3);
+    assert_eq!(strip_generation(e), 1234);
+    assert_eq!(world.get_alive(1234), id);
+
+    // new entities are still created in the configured range
+    let e2 = world.entity();
+    assert!(*e2.id() >= 5000);
+}
+
+#[test]
+fn world_name_of() {
+    let world = World::new();
+
+    let e = world.entity_named("Foo");
+    assert_eq!(world.name_of(e), Some("Foo"));
+
+    e.set_name("Bar");
+    assert_eq!(world.name_of(e), Some("Bar"));
+
+    let unnamed = world.entity();
+    assert_eq!(world.name_of(unnamed), None);
+
+    e.destruct();
+    assert_eq!(world.name_of(e), None);
+}
+
+#[test]
+fn world_version_save_restore() {
+    use flecs_ecs::sys;
+
+    let world = World::new();
+    assert_eq!(world.version(), None);
+
+    world.set_version(3);
+    assert_eq!(world.version(), Some(3));
+
+    // WorldVersion is registered inside the flecs_ecs module scope
+    let desc = sys::ecs_world_to_json_desc_t {
+        serialize_builtin: false,
+        serialize_modules: true,
+    };
+
+    let saved = unsafe {
+        let ptr = sys::ecs_world_to_json(world.ptr_mut(), &desc);
+        assert!(!ptr.is_null());
+        let json = std::ffi::CString::from(std::ffi::CStr::from_ptr(ptr));
+        sys::ecs_os_api.free_.unwrap()(ptr as *mut _);
+        json
+    };
+
+    let restored = World::new();
+    assert_eq!(restored.version(), None);
+
+    let end =
+        unsafe { sys::ecs_world_from_json(restored.ptr_mut(), saved.as_ptr(), std::ptr::null()) };
+    assert!(!end.is_null());
+    assert_eq!(restored.version(), Some(3));
+
+    restored.set_version(4);
+    assert_eq!(restored.version(), Some(4));
+    assert_eq!(world.version(), Some(3));
+}
+
+#[test]
+fn world_readonly_set_deferred() {
+    let world = World::new();
+    let e = world.entity().set(Position { x: 1, y: 2 });
+    let stage = world.stage(0);
+
+    let has_mass = world.readonly(false, || {
+        assert!(world.is_readonly());
+
+        e.mut_current_stage(stage)
+            .set(Mass { value: 5 })
+            .add::<Velocity>();
+
+        e.has::<Mass>() || e.has::<Velocity>()
+    });
+
+    assert!(!has_mass);
+    assert!(!world.is_readonly());
+    assert!(e.has::<Velocity>());
+    e.get::<&Mass>(|m| assert_eq!(m.value, 5));
+}
+
+#[test]
+fn world_each_expr() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 1, y: 2 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 30, y: 40 })
+        .set(Velocity { x: 3, y: 4 })
+        .add::<Mass>();
+    world.entity().set(Position { x: 50, y: 60 });
+
+    let expr = format!(
+        "{}, {}",
+        world.component::<Position>().path_w_sep(".", "").unwrap(),
+        world.component::<Velocity>().path_w_sep(".", "").unwrap()
+    );
+
+    let mut entities = Vec::new();
+    let query = world.each_expr(&expr, |it| {
+        let p = it.field::<Position>(0).unwrap();
+        let v = it.field::<Velocity>(1).unwrap();
+        for i in it.iter() {
+            assert_eq!(p[i].x, v[i].x * 10);
+            assert_eq!(p[i].y, v[i].y * 10);
+            entities.push(it.entity(i).id());
+        }
+    });
+
+    assert!(query.is_some());
+    assert_eq!(query.unwrap().field_count(), 2);
+    entities.sort();
+    assert_eq!(entities, vec![e1.id(), e2.id()]);
+}
+
+#[test]
+fn world_each_expr_invalid() {
+    let world = World::new();
+
+    let mut invoked = false;
+    let query = world.each_expr("Position,, (", |_| invoked = true);
+
+    assert!(query.is_none());
+    assert!(!invoked);
+}
+
+#[test]
+fn world_log_hook() {
+    use std::sync::{Arc, Mutex};
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let captured = messages.clone();
+    set_log_hook(move |level, msg| {
+        captured.lock().unwrap().push((level, msg.to_string()));
+        // hooks can be removed from within the hook
+        if msg.contains("LogHookTest") {
+            remove_log_hook();
+        }
+    });
+
+    let world = World::new();
+    assert!(world.each_expr("LogHookTest,, (", |_| {}).is_none());
+
+    remove_log_hook();
+
+    let messages = messages.lock().unwrap();
+    let (level, msg) = messages
+        .iter()
+        .find(|(_, msg)| msg.contains("LogHookTest"))
+        .expect("parse error was not passed to the log hook");
+    assert_eq!(*level, -3);
+    assert!(msg.contains("unexpected ','"));
+}
+
+#[test]
+fn world_set_entity_generation() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let id = e1.id();
+    e1.destruct();
+
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+    assert_ne!(e2.id(), id);
+    assert_eq!(*e2.id() as u32, *id as u32);
+
+    let e2 = world.set_entity_generation(e2, 0);
+    assert_eq!(e2.id(), id);
+    assert!(e2.is_alive());
+    e2.get::<&Position>(|p| assert_eq!(p.x, 3));
+
+    // the entity keeps its id across another delete and recreate cycle
+    e2.destruct();
+    let e3 = world.set_entity_generation(world.entity(), 0);
+    assert_eq!(e3.id(), id);
+
+    let e4 = world.set_entity_generation(world.entity(), 7);
+    assert_eq!(*e4.id() >> 32, 7);
+    assert!(e4.is_alive());
+}
+
+#[test]
+fn world_into_raw_from_raw() {
+    use flecs_ecs::sys;
+
+    let world = World::new();
+    let e = world.entity().set(Position { x: 1, y: 2 });
+    let e = e.id();
+
+    let raw = world.into_raw();
+    assert_eq!(unsafe { sys::flecs_poly_refcount(raw as *mut _) }, 1);
+
+    let world = unsafe { World::from_raw(raw) };
+    assert_eq!(world.ptr_mut(), raw);
+    assert!(world.is_alive(e));
+    world.entity_from_id(e).get::<&Position>(|p| {
+        assert_eq!(p.x, 1);
+        assert_eq!(p.y, 2);
+    });
+
+    // a clone keeps its own reference
+    let clone = world.clone();
+    let raw = world.into_raw();
+    assert_eq!(unsafe { sys::flecs_poly_refcount(raw as *mut _) }, 2);
+    drop(clone);
+
+    // the last reference destroys the world exactly once
+    drop(unsafe { World::from_raw(raw) });
+}
+
+#[test]
+fn world_from_raw_c_world() {
+    use flecs_ecs::sys;
+
+    let raw = unsafe { sys::ecs_init() };
+    let world = unsafe { World::from_raw(raw) };
+
+    let e = world.entity().set(Position { x: 3, y: 4 });
+    assert!(e.has::<Position>());
+}
+
+#[test]
+fn world_lookup_symbol() {
+    let world = World::new();
+
+    let pos = world.component::<Position>();
+    let symbol = pos.symbol();
+    assert!(!symbol.is_empty());
+
+    assert_eq!(world.lookup_symbol(symbol), pos.id());
+
+    // the symbol is independent of the name of the component
+    pos.set_name("Renamed");
+    assert_eq!(world.try_lookup_symbol(symbol).unwrap(), pos.id());
+
+    // names are not symbols
+    world.entity_named("Foo");
+    assert!(world.try_lookup("Foo").is_some());
+    assert!(world.try_lookup_symbol("Foo").is_none());
+    assert!(world.try_lookup_symbol("NotASymbol").is_none());
+}
+
+#[test]
+fn world_each_entity_parity_with_query() {
+    let world = World::new();
+
+    world
+        .component::<Position>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let base = world.prefab().set(Position { x: 100, y: 0 });
+    let entities: Vec<_> = (0..5)
+        .map(|i| world.entity().set(Position { x: i, y: i }))
+        .collect();
+    let inst = world.entity().is_a_id(base).add::<Velocity>();
+
+    let mut visited = vec![];
+    world.each_entity::<&mut Position>(|e, pos| {
+        visited.push(e.id());
+        if e != inst {
+            pos.y += 1;
+        }
+    });
+
+    let mut expected = vec![];
+    world
+        .new_query::<&Position>()
+        .each_entity(|e, _| expected.push(e.id()));
+
+    assert_eq!(visited, expected);
+    assert!(visited.contains(&inst.id()));
+    for (i, e) in entities.iter().enumerate() {
+        e.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (i as i32, i as i32 + 1)));
+    }
+    base.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (100, 0)));
+}
+
+#[test]
+fn world_get_singleton_mut() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let world = World::new();
+    world.set(Position { x: 10, y: 20 });
+
+    let set_count = Arc::new(AtomicUsize::new(0));
+    let observed = set_count.clone();
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(move |e, p| {
+            assert_eq!(e, e.world().singleton::<Position>());
+            assert_eq!((p.x, p.y), (11, 22));
+            observed.fetch_add(1, Ordering::SeqCst);
+        });
+
+    world.get_singleton_mut::<Position>(|p| {
+        p.x += 1;
+        p.y += 2;
+    });
+
+    assert_eq!(set_count.load(Ordering::SeqCst), 1);
+    world.get::<&Position>(|p| assert_eq!((p.x, p.y), (11, 22)));
+}
+
+#[test]
+fn world_set_lookup_path() {
+    let world = World::new();
+
+    let scope = world.entity_named("scope");
+    let foo = world.entity_named("Foo").child_of_id(scope);
+
+    assert!(world.try_lookup_recursive("Foo").is_none());
+
+    let previous = world.set_lookup_path([scope]);
+    assert_eq!(world.lookup_recursive("Foo"), foo);
+    assert!(world.try_lookup("Foo").is_none());
+
+    let replaced = world.set_lookup_path(previous.iter().copied());
+    assert_eq!(replaced, vec![scope.id()]);
+    assert!(world.try_lookup_recursive("Foo").is_none());
+}
+
+#[test]
+fn world_each_in_subtree() {
+    let world = World::new();
+
+    let panel_a = world.entity().set(Position { x: 0, y: 0 });
+    let a1 = world
+        .entity()
+        .child_of_id(panel_a)
+        .set(Position { x: 1, y: 0 });
+    let a2 = world.entity().child_of_id(a1).set(Position { x: 2, y: 0 });
+
+    let panel_b = world.entity().set(Position { x: 0, y: 0 });
+    let b1 = world
+        .entity()
+        .child_of_id(panel_b)
+        .set(Position { x: 1, y: 0 });
+    let b2 = world.entity().child_of_id(b1).set(Position { x: 2, y: 0 });
+
+    let mut visited = vec![];
+    world.each_in_subtree::<&mut Position>(panel_a, |e, p| {
+        p.y = 10;
+        visited.push(e.id());
+    });
+
+    visited.sort();
+    let mut expected = vec![a1.id(), a2.id()];
+    expected.sort();
+    assert_eq!(visited, expected);
+
+    a1.get::<&Position>(|p| assert_eq!(p.y, 10));
+    a2.get::<&Position>(|p| assert_eq!(p.y, 10));
+    for e in [panel_a, panel_b, b1, b2] {
+        e.get::<&Position>(|p| assert_eq!(p.y, 0));
+    }
+}
+
+#[test]
+fn world_stage_ids() {
+    let world = World::new();
+
+    assert_eq!(world.get_stage_count(), 1);
+    assert_eq!(world.stage_id(), 0);
+
+    world.set_stage_count(4);
+    assert_eq!(world.get_stage_count(), 4);
+
+    for id in 0..world.get_stage_count() {
+        let stage = world.stage(id);
+        assert!(stage.is_stage());
+        assert_eq!(stage.stage_id(), id);
+        assert_eq!(stage.get_stage_count(), 4);
+    }
+
+    // the world itself is not a stage and always reports stage 0
+    assert!(!world.is_stage());
+    assert_eq!(world.stage_id(), 0);
+}
@@ -0,0 +1,65 @@
+//! Allocation checks for iterators that are left unfinished.
+//!
+//! The allocation counters of the OS API are process-wide, so these tests live in
+//! their own binary instead of the parallel `flecs` test suite.
+
+use flecs_ecs::prelude::*;
+use flecs_ecs::sys;
+
+#[derive(Component)]
+struct Position {
+    x: i32,
+}
+
+fn outstanding_allocs() -> (i64, i64) {
+    unsafe {
+        (
+            sys::ecs_os_api_malloc_count + sys::ecs_os_api_calloc_count
+                - sys::ecs_os_api_free_count,
+            sys::ecs_stack_allocator_alloc_count - sys::ecs_stack_allocator_free_count,
+        )
+    }
+}
+
+#[test]
+fn query_run_break_early_no_leak() {
+    let world = World::new();
+    for x in 0..4 {
+        world.entity().set(Position { x });
+    }
+
+    let q = world.query::<&Position>().build();
+    let q_cached = world.query::<&Position>().set_cached().build();
+
+    let run_early_exit = || {
+        q.run(|mut it| {
+            it.next();
+        });
+        q.run(|_it| {});
+        q_cached.run(|mut it| {
+            it.next();
+        });
+        q.run_each(
+            |mut it| {
+                it.next();
+            },
+            |p| assert!(p.x >= 0),
+        );
+        q.run_each_entity(
+            |mut it| {
+                it.next();
+            },
+            |_e, _p| {},
+        );
+    };
+
+    // let the world allocate its iterator stack and caches
+    run_early_exit();
+    let before = outstanding_allocs();
+
+    for _ in 0..1000 {
+        run_early_exit();
+    }
+
+    assert_eq!(outstanding_allocs(), before);
+}
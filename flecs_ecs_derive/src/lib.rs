@@ -105,6 +105,74 @@ pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
     output.into()
 }
 
+/// `Meta` macro for registering the reflection data of Flecs ECS components.
+///
+/// When a struct is decorated with `#[derive(Component, Meta)]`, its fields are registered as members
+/// of the component when the component is registered with a world. This allows the component to be
+/// serialized, for example to JSON, and inspected with the explorer.
+///
+/// - Only structs with named fields are supported.
+/// - Field types must be primitive types that implement `MetaPrimitive`, such as `f32`, `i32` or `bool`.
+/// - Member offsets are taken from the Rust layout of the struct, and members are registered in
+///   the order of their offset.
+///
+/// ## Example:
+///
+/// ```ignore
+/// #[derive(Component, Meta)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+/// ```
+#[proc_macro_derive(Meta)]
+pub fn meta_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return quote! { compile_error!("Meta can only be derived for structs with named fields!"); }
+                    .into()
+            }
+        },
+        _ => return quote! { compile_error!("Meta can only be derived for structs!"); }.into(),
+    };
+
+    let members = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let member_name = ident.to_string();
+        quote_spanned! { syn::spanned::Spanned::span(ty) =>
+            (
+                ::core::mem::offset_of!(Self, #ident),
+                #member_name,
+                <#ty as flecs_ecs::core::flecs::meta::MetaPrimitive>::ID,
+            )
+        }
+    });
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let output = quote! {
+        impl #impl_generics flecs_ecs::core::component_registration::registration_traits::MetaComponent for #name #type_generics #where_clause {
+            fn meta(component: flecs_ecs::core::UntypedComponent<'_>) {
+                // members with explicit offsets have to be added in order of their offset
+                let mut members = [#( #members ),*];
+                members.sort_by_key(|member| member.0);
+
+                for (offset, name, type_id) in members {
+                    component.member_id(type_id, name, offset);
+                }
+            }
+        }
+    };
+
+    output.into()
+}
+
 fn generate_tag_trait(has_fields: bool) -> proc_macro2::TokenStream {
     if has_fields {
         quote! {
@@ -275,6 +343,17 @@ fn impl_cached_component_data_struct(
                     );
                 }
             }
+
+            fn __register_meta(component: flecs_ecs::core::UntypedComponent<'_>) {
+                const IMPLS_META: bool = {
+                    use flecs_ecs::core::utility::traits::DoesNotImpl;
+                    flecs_ecs::core::utility::types::ImplementsMeta::<#name>::IMPLS
+                };
+
+                if IMPLS_META {
+                    <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_META,#name> as flecs_ecs::core::component_registration::registration_traits::FlecsMetaType>::Type as flecs_ecs::core::component_registration::registration_traits::MetaComponent>::meta(component);
+                }
+            }
         }
     } else if contains_lifetime_bound && !contains_any_generic_type {
         quote! {
@@ -8032,6 +8032,9 @@ extern "C" {
         table: *mut ecs_table_t,
     ) -> i32;
 }
+extern "C" {
+    pub fn ecs_rust_table_is_locked(table: *const ecs_table_t) -> bool;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ecs_event_id_record_t {
@@ -102,3 +102,12 @@ error:
     return -1;
 }
 
+
+bool ecs_rust_table_is_locked(
+    const ecs_table_t* table)
+{
+    ecs_check(table != NULL, ECS_INVALID_PARAMETER, NULL);
+    return table->_->lock != 0;
+error:
+    return false;
+}
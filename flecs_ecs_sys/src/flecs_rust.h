@@ -22,3 +22,6 @@ FLECS_API
     ecs_id_t id,
     ecs_table_t* table);
 
+FLECS_API
+bool ecs_rust_table_is_locked(
+    const ecs_table_t* table);